@@ -0,0 +1,402 @@
+//! Live Field Map
+//!
+//! Draws the field on the brain's display with the robot's tracked pose, the trail it's driven
+//! recently, and the selected route's start pose and waypoints, so odometry can be checked in the
+//! pits by pushing the robot around or running a route. The map is drawn on a background task
+//! while it's shown, and only when the robot has moved.
+//!
+//! The panel beside the field shows the pose, and the most recent warnings and errors from the
+//! [log console](crate::logger::console), which the map takes the place of while it's shown.
+//! Tapping the panel clears the trail.
+//!
+//! Positions are in the routes' [field frame](crate::route#field-frame), with the route's own
+//! alliance wall along the bottom of the map.
+
+use alloc::{collections::VecDeque, format, rc::Rc, string::String, vec::Vec};
+use core::{cell::RefCell, time::Duration};
+
+use evian::math::Vec2;
+use vexide::{
+    devices::display::{Circle, Font, FontFamily, FontSize, Line, Rect, Text, TouchState},
+    float::Float,
+    prelude::{Display, Rgb},
+    sync::Mutex,
+    task::spawn,
+    time::sleep,
+};
+
+use crate::{
+    logger::console,
+    route::{Alliance, Route, StartPose},
+    theme::{WAR_EAGLE_BLUE, WAR_EAGLE_ORANGE, WAR_EAGLE_TEXT},
+    tracking::{wrap, Pose, SharedTracking},
+};
+
+/// How often the robot's pose is sampled and the map redrawn if it moved.
+const REDRAW_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Number of trail points kept. At one point per [`TRAIL_SPACING`], this covers a whole match.
+const TRAIL_LENGTH: usize = 400;
+
+/// Distance the robot has to move before a new trail point is added, in inches.
+const TRAIL_SPACING: f64 = 1.0;
+
+/// Number of warnings and errors shown in the panel.
+const WARNING_LINES: usize = 3;
+
+/// Characters that fit across the panel in the small monospace font.
+const PANEL_WIDTH: usize = 25;
+
+/// Change in heading that redraws the map, in radians.
+const HEADING_CHANGE: f64 = 0.05;
+
+/// Half the width of the field, in inches.
+const HALF_FIELD: f64 = 72.0;
+
+/// Size of a foam tile, in inches.
+const TILE: f64 = 24.0;
+
+/// Width and height of the field on screen, in pixels.
+const FIELD_SIZE: i16 = Display::VERTICAL_RESOLUTION;
+
+/// Pixels per inch.
+const SCALE: f64 = FIELD_SIZE as f64 / (2.0 * HALF_FIELD);
+
+/// Radius of the robot's outline, in inches.
+const ROBOT_RADIUS: f64 = 9.0;
+
+const PANEL_LEFT: i16 = FIELD_SIZE + 8;
+const LINE_HEIGHT: i16 = 24;
+const WARNING_LINE_HEIGHT: i16 = 21;
+
+const TILE_COLOR: Rgb<u8> = Rgb::new(70, 70, 70);
+const TILE_LINE_COLOR: Rgb<u8> = Rgb::new(100, 100, 100);
+const NEUTRAL_COLOR: Rgb<u8> = Rgb::new(242, 194, 0);
+
+static MAP: Mutex<FieldMap> = Mutex::new(FieldMap {
+    trail: VecDeque::new(),
+    route: None,
+    dirty: false,
+    enabled: false,
+    shown: false,
+});
+
+struct FieldMap {
+    trail: VecDeque<Vec2<f64>>,
+    route: Option<RoutePlan>,
+    dirty: bool,
+    enabled: bool,
+    shown: bool,
+}
+
+/// What the map shows of the selected route.
+struct RoutePlan {
+    name: &'static str,
+    alliance: Alliance,
+    start: Option<StartPose>,
+    waypoints: Vec<Vec2<f64>>,
+}
+
+/// Starts following `tracking` so the map can be shown.
+///
+/// The map is hidden until [`show`] is called, but the trail is recorded from now on. The display
+/// is shared so other code can still draw to it while the map is hidden.
+pub fn enable(display: Rc<RefCell<Display>>, tracking: SharedTracking) {
+    let Some(mut map) = MAP.try_lock() else {
+        return;
+    };
+
+    if map.enabled {
+        return;
+    }
+
+    map.enabled = true;
+    drop(map);
+
+    spawn(async move {
+        let mut drawn: Option<Pose> = None;
+        let mut drawn_warnings = console::revision();
+        let mut was_pressed = false;
+
+        loop {
+            sleep(REDRAW_INTERVAL).await;
+
+            let Some(mut map) = MAP.try_lock() else {
+                continue;
+            };
+
+            let pose = tracking.pose();
+            if map
+                .trail
+                .back()
+                .is_none_or(|last| pose.position.distance(*last) >= TRAIL_SPACING)
+            {
+                if map.trail.len() >= TRAIL_LENGTH {
+                    map.trail.pop_front();
+                }
+                map.trail.push_back(pose.position);
+            }
+
+            if !map.shown {
+                drawn = None;
+                continue;
+            }
+
+            // Skip this redraw if someone else is drawing, and try again next time.
+            let Ok(mut display) = display.try_borrow_mut() else {
+                continue;
+            };
+
+            let touch = display.touch_status();
+            let pressed = touch.state != TouchState::Released;
+            if pressed && !was_pressed && touch.x >= FIELD_SIZE {
+                map.trail.clear();
+                map.trail.push_back(pose.position);
+                map.dirty = true;
+            }
+            was_pressed = pressed;
+
+            let moved = drawn.is_none_or(|drawn| {
+                pose.position.distance(drawn.position) >= TRAIL_SPACING / 2.0
+                    || wrap(pose.heading.as_radians() - drawn.heading.as_radians()).abs()
+                        >= HEADING_CHANGE
+            });
+            let warnings = console::revision();
+            if map.dirty || moved || warnings != drawn_warnings {
+                draw(&mut display, &map, pose);
+                map.dirty = false;
+                drawn = Some(pose);
+                drawn_warnings = warnings;
+            }
+        }
+    })
+    .detach();
+}
+
+/// Shows the map on the brain's display in place of the log console until [`hide`] is called.
+/// Recent warnings and errors are still shown beside the map.
+pub fn show() {
+    if let Some(mut map) = MAP.try_lock() {
+        if !map.shown {
+            console::hide();
+            map.shown = true;
+            map.dirty = true;
+        }
+    }
+}
+
+/// Stops drawing the map (such as before the route selector is shown), and gives the display
+/// back to the log console.
+pub fn hide() {
+    if let Some(mut map) = MAP.try_lock() {
+        if map.shown {
+            map.shown = false;
+            console::show();
+        }
+    }
+}
+
+/// Shows `route`'s start pose and waypoints on the map, and clears the trail so it only shows
+/// the run that's about to start.
+pub fn set_route<R>(route: &dyn Route<R>) {
+    if let Some(mut map) = MAP.try_lock() {
+        map.route = Some(RoutePlan {
+            name: route.name(),
+            alliance: route.alliance(),
+            start: route.start(),
+            waypoints: route.waypoints(),
+        });
+        map.trail.clear();
+        map.dirty = true;
+    }
+}
+
+/// Clears the trail, the same as tapping the panel beside the field.
+pub fn clear_trail() {
+    if let Some(mut map) = MAP.try_lock() {
+        map.trail.clear();
+        map.dirty = true;
+    }
+}
+
+/// Converts a field position in inches to a point on the display.
+fn point(position: Vec2<f64>) -> [i16; 2] {
+    [
+        ((position.x + HALF_FIELD) * SCALE) as i16,
+        ((HALF_FIELD - position.y) * SCALE) as i16,
+    ]
+}
+
+fn draw(display: &mut Display, map: &FieldMap, pose: Pose) {
+    let alliance = map
+        .route
+        .as_ref()
+        .map_or(Alliance::Red, |route| route.alliance);
+
+    display.fill(
+        &Rect::new(
+            [0, 0],
+            [Display::HORIZONTAL_RESOLUTION, Display::VERTICAL_RESOLUTION],
+        ),
+        Rgb::new(0, 0, 0),
+    );
+    draw_field(display, alliance);
+
+    if let Some(route) = &map.route {
+        if let Some(start) = route.start {
+            display.stroke(
+                &Circle::new(point(start.position), (ROBOT_RADIUS * SCALE) as u16),
+                WAR_EAGLE_BLUE,
+            );
+        }
+        let path = route
+            .start
+            .map(|start| start.position)
+            .into_iter()
+            .chain(route.waypoints.iter().copied());
+        for (from, to) in path.clone().zip(path.skip(1)) {
+            display.fill(&Line::new(point(from), point(to)), WAR_EAGLE_BLUE);
+        }
+        for &waypoint in &route.waypoints {
+            display.fill(&Circle::new(point(waypoint), 2), WAR_EAGLE_BLUE);
+        }
+    }
+
+    for (from, to) in map.trail.iter().zip(map.trail.iter().skip(1)) {
+        display.fill(&Line::new(point(*from), point(*to)), WAR_EAGLE_ORANGE);
+    }
+
+    // Robot, with a line showing which way it's facing.
+    let center = point(pose.position);
+    let heading = pose.heading.as_radians();
+    let front = point(Vec2::new(
+        pose.position.x + ROBOT_RADIUS * heading.cos(),
+        pose.position.y + ROBOT_RADIUS * heading.sin(),
+    ));
+    display.stroke(
+        &Circle::new(center, (ROBOT_RADIUS * SCALE) as u16),
+        WAR_EAGLE_TEXT,
+    );
+    display.fill(&Line::new(center, front), WAR_EAGLE_TEXT);
+
+    draw_panel(display, map, pose);
+}
+
+fn draw_field(display: &mut Display, alliance: Alliance) {
+    let opponent = match alliance {
+        Alliance::Red => Alliance::Blue,
+        Alliance::Blue => Alliance::Red,
+    };
+
+    display.fill(&Rect::new([0, 0], [FIELD_SIZE, FIELD_SIZE]), TILE_COLOR);
+    for line in 1..6 {
+        let offset = (f64::from(line) * TILE * SCALE) as i16;
+
+        display.fill(
+            &Line::new([offset, 0], [offset, FIELD_SIZE]),
+            TILE_LINE_COLOR,
+        );
+        display.fill(
+            &Line::new([0, offset], [FIELD_SIZE, offset]),
+            TILE_LINE_COLOR,
+        );
+    }
+
+    // Autonomous line
+    display.fill(
+        &Line::new(
+            point(Vec2::new(-HALF_FIELD, 0.0)),
+            point(Vec2::new(HALF_FIELD, 0.0)),
+        ),
+        WAR_EAGLE_TEXT,
+    );
+
+    // Ladder, with the high stake at its center
+    let corners =
+        [(TILE, 0.0), (0.0, TILE), (-TILE, 0.0), (0.0, -TILE)].map(|(x, y)| point(Vec2::new(x, y)));
+    for (index, corner) in corners.iter().enumerate() {
+        display.fill(
+            &Line::new(*corner, corners[(index + 1) % corners.len()]),
+            WAR_EAGLE_TEXT,
+        );
+    }
+    stake(display, Vec2::new(0.0, 0.0), NEUTRAL_COLOR);
+
+    // Wall stakes
+    stake(display, Vec2::new(0.0, -HALF_FIELD), alliance.color());
+    stake(display, Vec2::new(0.0, HALF_FIELD), opponent.color());
+    stake(display, Vec2::new(-HALF_FIELD, 0.0), NEUTRAL_COLOR);
+    stake(display, Vec2::new(HALF_FIELD, 0.0), NEUTRAL_COLOR);
+}
+
+fn stake(display: &mut Display, position: Vec2<f64>, color: Rgb<u8>) {
+    display.fill(&Circle::new(point(position), 3), color);
+}
+
+fn draw_panel(display: &mut Display, map: &FieldMap, pose: Pose) {
+    let font = Font::new(FontSize::SMALL, FontFamily::Monospace);
+    let lines = [
+        map.route.as_ref().map_or_else(
+            || String::from("No route"),
+            |route| String::from(route.name),
+        ),
+        format!("X {:>7.1} in", pose.position.x),
+        format!("Y {:>7.1} in", pose.position.y),
+        format!("H {:>7.1} deg", pose.heading.as_degrees()),
+    ];
+
+    display.fill(
+        &Rect::new(
+            [FIELD_SIZE, 0],
+            [Display::HORIZONTAL_RESOLUTION, LINE_HEIGHT],
+        ),
+        WAR_EAGLE_BLUE,
+    );
+    for (row, line) in (0..).zip(&lines) {
+        display.draw_text(
+            &Text::new(line, font, [PANEL_LEFT, 2 + row * LINE_HEIGHT]),
+            WAR_EAGLE_TEXT,
+            None,
+        );
+    }
+
+    let warnings = console::recent_lines(WARNING_LINES, PANEL_WIDTH);
+    let top = 4 * LINE_HEIGHT + 2;
+    display.draw_text(
+        &Text::new(
+            if warnings.is_empty() {
+                "No warnings"
+            } else {
+                "Warnings"
+            },
+            font,
+            [PANEL_LEFT, top],
+        ),
+        WAR_EAGLE_ORANGE,
+        None,
+    );
+    for (row, (line, color)) in (1..).zip(&warnings) {
+        display.draw_text(
+            &Text::new(line, font, [PANEL_LEFT, top + row * WARNING_LINE_HEIGHT]),
+            *color,
+            None,
+        );
+    }
+
+    // Legend
+    let bottom = Display::VERTICAL_RESOLUTION - 2 * LINE_HEIGHT;
+    display.draw_text(
+        &Text::new("Planned", font, [PANEL_LEFT, bottom]),
+        WAR_EAGLE_BLUE,
+        None,
+    );
+    display.draw_text(
+        &Text::new(
+            "Trail (tap to clear)",
+            font,
+            [PANEL_LEFT, bottom + LINE_HEIGHT],
+        ),
+        WAR_EAGLE_ORANGE,
+        None,
+    );
+}
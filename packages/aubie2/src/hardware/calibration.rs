@@ -1,42 +1,303 @@
 use alloc::format;
+use core::{fmt, ops::RangeInclusive, pin::pin, time::Duration};
 
-use log::{error, info};
+use evian::prelude::{Differential, Voltages};
+use futures::future::{select, Either};
+use log::{error, info, warn};
 use vexide::{
-    devices::display::{Font, FontFamily, FontSize, HAlign, Text, VAlign},
-    prelude::{Controller, Display, InertialSensor, Rgb},
-    time::Instant,
+    devices::{
+        display::{Font, FontFamily, FontSize, HAlign, Rect, Text, VAlign},
+        smart::imu::InertialError,
+    },
+    prelude::{Controller, Display, InertialSensor, Motor, Rgb, SmartDevice},
+    time::{sleep, Instant},
 };
 
+/// Voltage the drivetrain spins at while measuring the IMU scale.
+const SCALE_SPIN_VOLTAGE: f64 = 6.0;
+
+/// How far (in degrees) short of the final turn the drivetrain stops spinning on its own.
+const SCALE_STOP_MARGIN: f64 = 20.0;
+
+/// Maximum voltage used when nudging the robot into alignment with the left stick.
+const SCALE_NUDGE_VOLTAGE: f64 = 3.0;
+
+/// Range of scale factors a working IMU measures. Anything outside it means the IMU stopped
+/// reporting mid-turn or the robot wasn't turned the whole way, not a real correction.
+const SCALE_RANGE: RangeInclusive<f64> = 0.9..=1.1;
+
+/// Settings for [`calibrate_imu`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ImuCalibration {
+    /// Number of times calibration is attempted before giving up.
+    pub attempts: usize,
+
+    /// Maximum duration of a single calibration attempt.
+    pub timeout: Duration,
+
+    /// How long the heading is sampled for after calibrating to measure drift.
+    pub drift_sample_duration: Duration,
+
+    /// Largest acceptable static drift in degrees per second.
+    pub max_drift: f64,
+}
+
+impl ImuCalibration {
+    pub const DEFAULT: Self = Self {
+        attempts: 3,
+        timeout: Duration::from_secs(4),
+        drift_sample_duration: Duration::from_secs(3),
+        max_drift: 0.05,
+    };
+}
+
+impl Default for ImuCalibration {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Reason the final IMU calibration attempt was rejected.
+#[derive(Debug)]
+pub enum CalibrationError {
+    /// The sensor reported an error while calibrating or sampling.
+    Imu(InertialError),
+
+    /// Calibration did not finish within [`ImuCalibration::timeout`].
+    TimedOut,
+
+    /// The sensor drifted faster than [`ImuCalibration::max_drift`] (in °/s) while stationary.
+    Drift(f64),
+
+    /// The measured scale factor was outside the range a working IMU measures.
+    Scale(f64),
+}
+
+impl fmt::Display for CalibrationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Imu(err) => write!(f, "IMU error: {err}"),
+            Self::TimedOut => write!(f, "calibration timed out"),
+            Self::Drift(rate) => write!(f, "drift of {rate:.3}°/s exceeds limit"),
+            Self::Scale(scale) => write!(f, "scale factor of {scale:.5} is out of range"),
+        }
+    }
+}
+
+impl From<InertialError> for CalibrationError {
+    fn from(err: InertialError) -> Self {
+        Self::Imu(err)
+    }
+}
+
+/// Calibrates an IMU, retrying until a calibration passes drift verification.
+///
+/// The robot must be stationary for the entire duration of this function. Progress is reported
+/// on the first line of the controller screen and on the brain display.
 pub async fn calibrate_imu(
     controller: &mut Controller,
     display: &mut Display,
     imu: &mut InertialSensor,
-) {
-    info!("Calibrating IMU");
+    settings: ImuCalibration,
+) -> Result<(), CalibrationError> {
+    let mut last_error = CalibrationError::TimedOut;
+
+    for attempt in 1..=settings.attempts {
+        info!(
+            "Calibrating IMU (attempt {}/{})",
+            attempt, settings.attempts
+        );
+        show_status(
+            controller,
+            display,
+            &format!("Calibrating {}/{}", attempt, settings.attempts),
+            Rgb::new(255, 255, 255),
+        );
+
+        let imu_calibration_start = Instant::now();
+
+        let result = match select(pin!(imu.calibrate()), pin!(sleep(settings.timeout))).await {
+            Either::Left((result, _)) => result.map_err(CalibrationError::from),
+            Either::Right(_) => Err(CalibrationError::TimedOut),
+        };
+
+        if let Err(err) = result {
+            warn!("Calibration attempt {} failed: {}", attempt, err);
+            last_error = err;
+            continue;
+        }
+
+        let imu_calibration_elapsed = imu_calibration_start.elapsed();
+        info!("Calibration completed in {:?}.", imu_calibration_elapsed);
+
+        // Sample the heading while stationary to catch calibrations that "succeeded" with a
+        // bad bias estimate.
+        show_status(
+            controller,
+            display,
+            "Checking drift...",
+            Rgb::new(255, 255, 255),
+        );
+
+        match measure_drift(imu, settings.drift_sample_duration).await {
+            Ok(drift) if drift <= settings.max_drift => {
+                info!("IMU drift is {:.3}°/s.", drift);
+                show_status(
+                    controller,
+                    display,
+                    &format!("{:?} {:.3}°/s", imu_calibration_elapsed, drift),
+                    Rgb::new(0, 255, 0),
+                );
+
+                return Ok(());
+            }
+            Ok(drift) => {
+                warn!(
+                    "IMU drift of {:.3}°/s exceeds {:.3}°/s, recalibrating.",
+                    drift, settings.max_drift
+                );
+                last_error = CalibrationError::Drift(drift);
+            }
+            Err(err) => {
+                warn!("Failed to sample IMU drift: {}", err);
+                last_error = err.into();
+            }
+        }
+    }
+
+    error!("Calibration fail! ({})", last_error);
+    show_status(
+        controller,
+        display,
+        "Calibration fail!",
+        Rgb::new(255, 0, 0),
+    );
+
+    Err(last_error)
+}
+
+/// Measures a rotation correction factor for an IMU by spinning the robot in place.
+///
+/// The robot should first be lined up against a physical reference (such as a field tile seam or
+/// wall) and A pressed. The drivetrain then spins `turns` full counterclockwise rotations, stopping
+/// just short of the reference, where it should be nudged back into exact alignment with the
+/// left stick before pressing A again.
+///
+/// The returned factor is meant to be passed to [`ScaledImu::new`](super::ScaledImu::new). A
+/// factor more than 10% from 1.0 is rejected with [`CalibrationError::Scale`], so the caller can
+/// keep its previous scale.
+pub async fn calibrate_imu_scale(
+    controller: &mut Controller,
+    motors: &mut Differential,
+    imu: &InertialSensor,
+    turns: u32,
+) -> Result<f64, CalibrationError> {
+    info!("Measuring IMU scale over {} turns", turns);
+
+    _ = controller.screen.try_set_text("Align, press A     ", 1, 1);
+    wait_for_alignment(controller, motors).await;
+
+    let start_rotation = imu.rotation()?;
+    let expected = f64::from(turns) * 360.0;
+
+    _ = controller.screen.try_set_text("Spinning...        ", 1, 1);
+    _ = motors.set_voltages(Voltages::from_arcade(0.0, -SCALE_SPIN_VOLTAGE));
+
+    loop {
+        match imu.rotation() {
+            Ok(rotation) if (rotation - start_rotation).abs() >= expected - SCALE_STOP_MARGIN => {
+                break;
+            }
+            Ok(_) => {}
+            Err(err) => {
+                _ = motors.set_voltages(Voltages::from_arcade(0.0, 0.0));
+                error!("IMU scale measurement failed: {}", err);
+                return Err(err.into());
+            }
+        }
+
+        sleep(InertialSensor::UPDATE_INTERVAL).await;
+    }
+
+    _ = motors.set_voltages(Voltages::from_arcade(0.0, 0.0));
+
+    _ = controller.screen.try_set_text("Nudge, press A     ", 1, 1);
+    wait_for_alignment(controller, motors).await;
+
+    let measured = (imu.rotation()? - start_rotation).abs();
+    let scale = expected / measured;
+
+    info!(
+        "IMU measured {:.2}° over {:.0}°, scale factor is {:.5}.",
+        measured, expected, scale
+    );
+
+    // A NaN or infinite scale (from an unplugged or unturned IMU) fails this too.
+    if !SCALE_RANGE.contains(&scale) {
+        error!(
+            "IMU scale measurement failed: {}",
+            CalibrationError::Scale(scale)
+        );
+        _ = controller.screen.try_set_text("Scale rejected     ", 1, 1);
+
+        return Err(CalibrationError::Scale(scale));
+    }
+
     _ = controller
         .screen
-        .try_set_text("Calibrating...", 1, 1);
-    let imu_calibration_start = Instant::now();
-    
-    if imu.calibrate().await.is_err() {
-        error!("Calibration fail!");
-        _ = controller
-            .screen
-            .try_set_text("Calibration fail!    ", 1, 1);
-        return;
+        .try_set_text(format!("Scale: {:<12.5}", scale), 1, 1);
+
+    Ok(scale)
+}
+
+/// Lets the driver turn the robot in place with the left stick until A is pressed.
+async fn wait_for_alignment(controller: &mut Controller, motors: &mut Differential) {
+    loop {
+        let state = controller.state().unwrap_or_default();
+
+        if state.button_a.is_now_pressed() {
+            break;
+        }
+
+        _ = motors.set_voltages(Voltages::from_arcade(
+            0.0,
+            state.left_stick.x() * SCALE_NUDGE_VOLTAGE,
+        ));
+
+        sleep(Motor::UPDATE_INTERVAL).await;
     }
 
-    let imu_calibration_elapsed = imu_calibration_start.elapsed();
+    _ = motors.set_voltages(Voltages::from_arcade(0.0, 0.0));
+}
+
+/// Measures the absolute rate of heading change (in °/s) over the given duration.
+async fn measure_drift(imu: &InertialSensor, duration: Duration) -> Result<f64, InertialError> {
+    let start_rotation = imu.rotation()?;
+    let start = Instant::now();
+
+    sleep(duration).await;
 
-    info!("Calibration completed in {:?}.", imu_calibration_elapsed);
+    let delta = imu.rotation()? - start_rotation;
 
+    Ok(delta.abs() / start.elapsed().as_secs_f64())
+}
+
+fn show_status(controller: &mut Controller, display: &mut Display, status: &str, color: Rgb<u8>) {
+    // Trailing spaces overwrite anything left over from a longer previous status.
     _ = controller
         .screen
-        .try_set_text(format!("{:?}    ", imu_calibration_elapsed), 1, 1);
+        .try_set_text(format!("{:<19}", status), 1, 1);
 
+    display.fill(
+        &Rect::new(
+            [0, 0],
+            [Display::HORIZONTAL_RESOLUTION, Display::VERTICAL_RESOLUTION],
+        ),
+        Rgb::new(0, 0, 0),
+    );
     display.draw_text(
         &Text::new_aligned(
-            &format!("{:?}", imu_calibration_elapsed),
+            status,
             Font::new(FontSize::LARGE, FontFamily::Monospace),
             [
                 Display::HORIZONTAL_RESOLUTION / 2,
@@ -45,7 +306,7 @@ pub async fn calibrate_imu(
             HAlign::Center,
             VAlign::Center,
         ),
-        Rgb::new(255, 255, 255),
+        color,
         None,
     );
 }
@@ -0,0 +1,231 @@
+use core::cell::RefCell;
+
+use evian::{math::Angle, tracking::Gyro};
+use log::{info, warn};
+
+use crate::tracking::wrap;
+
+/// Heading source combining two gyros.
+///
+/// The fused heading is built by integrating the change in heading reported by each sensor
+/// between updates. When both sensors are healthy their changes are averaged, and if they
+/// disagree by more than a threshold in a single update the larger of the two is rejected as a
+/// spike. If one sensor errors (such as when it's unplugged), the other continues tracking on
+/// its own without a jump in heading, and the failed sensor rejoins once it recovers.
+pub struct DualGyro<G: Gyro> {
+    gyros: [G; 2],
+    max_disagreement: Angle,
+    state: RefCell<FusionState>,
+}
+
+struct FusionState {
+    heading: Option<f64>,
+    last_readings: [Option<f64>; 2],
+    healthy: [bool; 2],
+}
+
+impl<G: Gyro> DualGyro<G> {
+    /// Largest difference between the two sensors' heading changes in a single update before
+    /// one of them is treated as an outlier.
+    pub const DEFAULT_MAX_DISAGREEMENT: Angle = Angle::from_degrees(2.0);
+
+    pub fn new(primary: G, secondary: G) -> Self {
+        Self::with_max_disagreement(primary, secondary, Self::DEFAULT_MAX_DISAGREEMENT)
+    }
+
+    pub fn with_max_disagreement(primary: G, secondary: G, max_disagreement: Angle) -> Self {
+        Self {
+            gyros: [primary, secondary],
+            max_disagreement,
+            state: RefCell::new(FusionState {
+                heading: None,
+                last_readings: [None; 2],
+                healthy: [true; 2],
+            }),
+        }
+    }
+
+    pub const fn primary(&self) -> &G {
+        &self.gyros[0]
+    }
+
+    pub const fn secondary(&self) -> &G {
+        &self.gyros[1]
+    }
+}
+
+impl<G: Gyro> Gyro for DualGyro<G> {
+    type Error = G::Error;
+
+    fn heading(&self) -> Result<Angle, Self::Error> {
+        let mut state = self.state.borrow_mut();
+        let readings = [self.gyros[0].heading(), self.gyros[1].heading()];
+
+        let mut deltas = [None; 2];
+        for (i, reading) in readings.iter().enumerate() {
+            let reading = reading.as_ref().ok().map(|heading| heading.as_radians());
+
+            if reading.is_some() != state.healthy[i] {
+                state.healthy[i] = reading.is_some();
+
+                if reading.is_some() {
+                    info!("Gyro {} recovered.", i);
+                } else {
+                    warn!("Gyro {} lost, relying on the remaining sensor.", i);
+                }
+            }
+
+            if let (Some(reading), Some(last)) = (reading, state.last_readings[i]) {
+                deltas[i] = Some(wrap(reading - last));
+            }
+            state.last_readings[i] = reading;
+        }
+
+        let delta = match deltas {
+            [Some(a), Some(b)] if (a - b).abs() > self.max_disagreement.as_radians() => {
+                warn!(
+                    "Gyros disagree by {:.2}°, rejecting outlier.",
+                    (a - b).abs().to_degrees()
+                );
+
+                if a.abs() < b.abs() {
+                    a
+                } else {
+                    b
+                }
+            }
+            [Some(a), Some(b)] => (a + b) / 2.0,
+            [Some(delta), None] | [None, Some(delta)] => delta,
+            [None, None] => 0.0,
+        };
+
+        // Nothing to report if both sensors are down. The fused heading is kept so tracking
+        // can pick up where it left off once either of them comes back.
+        let [primary, secondary] = readings;
+        if let (Err(err), Err(_)) = (primary, secondary) {
+            return Err(err);
+        }
+
+        let heading = match state.heading {
+            Some(heading) => heading + delta,
+            // Start from whichever sensor responded first so the fused heading lines up with
+            // what a single sensor would have reported.
+            None => state.last_readings[0]
+                .or(state.last_readings[1])
+                .unwrap_or_default(),
+        };
+        state.heading = Some(heading);
+
+        Ok(Angle::from_radians(heading))
+    }
+
+    fn angular_velocity(&self) -> Result<f64, Self::Error> {
+        match (
+            self.gyros[0].angular_velocity(),
+            self.gyros[1].angular_velocity(),
+        ) {
+            (Ok(a), Ok(b)) => Ok((a + b) / 2.0),
+            (Ok(velocity), Err(_)) | (Err(_), Ok(velocity)) => Ok(velocity),
+            (Err(primary), Err(_)) => Err(primary),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cell::Cell;
+
+    use super::*;
+
+    /// Gyro reporting whatever heading (in degrees) it's set to, or an error if it's unset.
+    struct FakeGyro(Cell<Option<f64>>);
+
+    impl FakeGyro {
+        fn new(heading: f64) -> Self {
+            Self(Cell::new(Some(heading)))
+        }
+
+        fn set(&self, heading: Option<f64>) {
+            self.0.set(heading);
+        }
+    }
+
+    impl Gyro for FakeGyro {
+        type Error = ();
+
+        fn heading(&self) -> Result<Angle, ()> {
+            self.0.get().map(Angle::from_degrees).ok_or(())
+        }
+
+        fn angular_velocity(&self) -> Result<f64, ()> {
+            self.0.get().map(|_| 0.0).ok_or(())
+        }
+    }
+
+    fn set(gyro: &DualGyro<FakeGyro>, primary: Option<f64>, secondary: Option<f64>) -> f64 {
+        gyro.primary().set(primary);
+        gyro.secondary().set(secondary);
+        gyro.heading().unwrap().as_degrees()
+    }
+
+    fn assert_near(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-9,
+            "expected {expected}°, got {actual}°"
+        );
+    }
+
+    #[test]
+    fn starts_from_primary() {
+        let gyro = DualGyro::new(FakeGyro::new(90.0), FakeGyro::new(45.0));
+
+        assert_near(gyro.heading().unwrap().as_degrees(), 90.0);
+    }
+
+    #[test]
+    fn averages_agreeing_changes() {
+        let gyro = DualGyro::new(FakeGyro::new(0.0), FakeGyro::new(10.0));
+        gyro.heading().unwrap();
+
+        assert_near(set(&gyro, Some(1.0), Some(12.0)), 1.5);
+    }
+
+    #[test]
+    fn rejects_larger_change_when_gyros_disagree() {
+        let gyro = DualGyro::new(FakeGyro::new(0.0), FakeGyro::new(0.0));
+        gyro.heading().unwrap();
+
+        assert_near(set(&gyro, Some(1.0), Some(20.0)), 1.0);
+        assert_near(set(&gyro, Some(-30.0), Some(19.5)), 0.5);
+    }
+
+    #[test]
+    fn keeps_tracking_when_one_gyro_fails() {
+        let gyro = DualGyro::new(FakeGyro::new(0.0), FakeGyro::new(0.0));
+        gyro.heading().unwrap();
+
+        assert_near(set(&gyro, None, Some(5.0)), 5.0);
+        assert_near(set(&gyro, None, Some(8.0)), 8.0);
+        assert!(!gyro.state.borrow().healthy[0]);
+
+        // The primary rejoins from its own reading, without pulling the heading to it.
+        assert_near(set(&gyro, Some(100.0), Some(9.0)), 9.0);
+        assert_near(set(&gyro, Some(101.0), Some(10.0)), 10.0);
+        assert!(gyro.state.borrow().healthy[0]);
+    }
+
+    #[test]
+    fn errors_only_when_both_gyros_fail() {
+        let gyro = DualGyro::new(FakeGyro::new(0.0), FakeGyro::new(0.0));
+        assert_near(set(&gyro, Some(4.0), Some(4.0)), 4.0);
+
+        gyro.primary().set(None);
+        gyro.secondary().set(None);
+        assert!(gyro.heading().is_err());
+        assert!(gyro.angular_velocity().is_err());
+
+        // The fused heading picks up where it left off.
+        set(&gyro, Some(50.0), None);
+        assert_near(set(&gyro, Some(52.0), None), 6.0);
+    }
+}
@@ -0,0 +1,45 @@
+use evian::{math::Angle, tracking::Gyro};
+use vexide::{devices::smart::imu::InertialError, prelude::InertialSensor};
+
+/// Inertial sensor with a correction applied to its reported rotation.
+///
+/// IMUs tend to consistently under- or over-report full rotations by a small fraction. The
+/// correction factor for a given sensor can be measured with
+/// [`calibrate_imu_scale`](super::calibrate_imu_scale).
+pub struct ScaledImu {
+    imu: InertialSensor,
+    scale: f64,
+}
+
+impl ScaledImu {
+    pub const fn new(imu: InertialSensor, scale: f64) -> Self {
+        Self { imu, scale }
+    }
+
+    pub const fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    pub fn set_scale(&mut self, scale: f64) {
+        self.scale = scale;
+    }
+
+    pub const fn inner(&self) -> &InertialSensor {
+        &self.imu
+    }
+}
+
+impl Gyro for ScaledImu {
+    type Error = InertialError;
+
+    fn heading(&self) -> Result<Angle, Self::Error> {
+        // The correction has to be applied to the unwrapped rotation, otherwise the heading
+        // would jump every time the sensor wraps around 360°. The IMU's rotation is clockwise,
+        // so it's negated to match evian's counterclockwise convention.
+        Ok(Angle::from_degrees(-self.imu.rotation()? * self.scale))
+    }
+
+    fn angular_velocity(&self) -> Result<f64, Self::Error> {
+        Ok(Gyro::angular_velocity(&self.imu)? * self.scale)
+    }
+}
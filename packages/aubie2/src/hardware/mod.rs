@@ -1,5 +1,9 @@
 mod calibration;
+mod dual_gyro;
 mod encoder;
+mod imu;
 
-pub use calibration::calibrate_imu;
+pub use calibration::{calibrate_imu, calibrate_imu_scale, CalibrationError, ImuCalibration};
+pub use dual_gyro::DualGyro;
 pub use encoder::CustomEncoder;
+pub use imu::ScaledImu;
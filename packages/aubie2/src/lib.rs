@@ -1,9 +1,15 @@
 #![no_std]
-#![no_main]
 
 extern crate alloc;
 
+pub mod field_map;
 pub mod hardware;
 pub mod logger;
+pub mod route;
+pub mod selector;
+#[cfg(feature = "sim")]
+pub mod sim;
 pub mod subsystems;
+pub mod telemetry;
 pub mod theme;
+pub mod tracking;
@@ -0,0 +1,132 @@
+//! Controller Screen Alerts
+//!
+//! Shows the most recent warnings and errors on the controller's screen, newest first, so the
+//! driver finds out about problems during a match. Errors can also rumble the controller.
+//!
+//! The controller only accepts a screen update about every 50ms, so alerts are queued here and
+//! written out a line at a time by [`update`], which is called from whichever task owns the
+//! controller (usually driver control).
+
+use alloc::{collections::VecDeque, format, string::String};
+use core::time::Duration;
+
+use log::Level;
+use vexide::{prelude::Controller, sync::Mutex, time::Instant};
+
+use super::LogEntry;
+
+/// Number of lines on the controller's screen.
+const LINES: usize = 3;
+
+/// Characters that fit on one line of the controller's screen.
+const LINE_WIDTH: usize = 19;
+
+/// Minimum time between writes to the controller.
+const WRITE_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Rumble pattern played on errors.
+const RUMBLE_PATTERN: &str = ".";
+
+static ALERTS: Mutex<Alerts> = Mutex::new(Alerts {
+    lines: VecDeque::new(),
+    dirty: [false; LINES],
+    rumble_pending: false,
+    rumble: false,
+    enabled: false,
+    last_write: None,
+});
+
+struct Alerts {
+    lines: VecDeque<String>,
+    dirty: [bool; LINES],
+    rumble_pending: bool,
+    rumble: bool,
+    enabled: bool,
+    last_write: Option<Instant>,
+}
+
+/// Starts collecting warnings and errors to show on the controller, optionally rumbling it
+/// whenever an error is logged.
+pub fn enable(rumble: bool) {
+    if let Some(mut alerts) = ALERTS.try_lock() {
+        alerts.enabled = true;
+        alerts.rumble = rumble;
+    }
+}
+
+/// Writes the next pending change to the controller's screen, if the controller is ready for
+/// one.
+///
+/// This only does a single write per call, so it should be called regularly, such as once per
+/// iteration of the driver control loop.
+pub fn update(controller: &mut Controller) {
+    let Some(mut alerts) = ALERTS.try_lock() else {
+        return;
+    };
+
+    if alerts
+        .last_write
+        .is_some_and(|last_write| last_write.elapsed() < WRITE_INTERVAL)
+    {
+        return;
+    }
+
+    if alerts.rumble_pending {
+        alerts.rumble_pending = false;
+        alerts.last_write = Some(Instant::now());
+        _ = controller.try_rumble(RUMBLE_PATTERN);
+        return;
+    }
+
+    let Some(line) = alerts.dirty.iter().position(|&dirty| dirty) else {
+        return;
+    };
+    let text = alerts.lines.get(line).map_or("", String::as_str);
+
+    // Trailing spaces overwrite anything left over from a longer previous alert.
+    if controller
+        .screen
+        .try_set_text(format!("{:<1$}", text, LINE_WIDTH), line as u8 + 1, 1)
+        .is_ok()
+    {
+        alerts.dirty[line] = false;
+    }
+    alerts.last_write = Some(Instant::now());
+}
+
+/// Queues a record to show on the controller if it's a warning or error.
+pub(crate) fn write(entry: &LogEntry) {
+    if entry.level > Level::Warn {
+        return;
+    }
+
+    let Some(mut alerts) = ALERTS.try_lock() else {
+        return;
+    };
+
+    if !alerts.enabled {
+        return;
+    }
+
+    let prefix = match entry.level {
+        Level::Error => "E",
+        _ => "W",
+    };
+    let line: String = format!("{} {}", prefix, entry.message)
+        .chars()
+        .take(LINE_WIDTH)
+        .collect();
+
+    // A repeat of the latest alert doesn't need to take up another line.
+    if alerts.lines.front() == Some(&line) {
+        return;
+    }
+
+    alerts.lines.push_front(line);
+    alerts.lines.truncate(LINES);
+    alerts.dirty = [true; LINES];
+
+    if entry.level == Level::Error && alerts.rumble {
+        alerts.rumble_pending = true;
+    }
+}
@@ -0,0 +1,218 @@
+//! Brain Display Log Console
+//!
+//! Keeps the most recent warnings and errors and draws them as a scrolling panel on the brain's
+//! display, so problems like a lost sensor are visible on the field without a terminal attached.
+//! Drawing happens on a background task, and only when something new has been logged.
+
+use alloc::{collections::VecDeque, format, rc::Rc, string::String, vec::Vec};
+use core::{cell::RefCell, time::Duration};
+
+use log::Level;
+use vexide::{
+    devices::display::{Font, FontFamily, FontSize, Rect, Text},
+    prelude::{Display, Rgb},
+    sync::Mutex,
+    task::spawn,
+    time::sleep,
+};
+
+use super::LogEntry;
+use crate::theme::{WAR_EAGLE_BLUE, WAR_EAGLE_ORANGE, WAR_EAGLE_TEXT};
+
+/// Number of records kept and shown on the display.
+pub const CAPACITY: usize = 10;
+
+/// How often the display is checked for a pending redraw.
+const REDRAW_INTERVAL: Duration = Duration::from_millis(100);
+
+const HEADER_HEIGHT: i16 = 24;
+const LINE_HEIGHT: i16 = 21;
+const MARGIN: i16 = 6;
+
+/// Characters that fit on one line of the panel in the small monospace font.
+const LINE_WIDTH: usize = 52;
+
+static CONSOLE: Mutex<Console> = Mutex::new(Console {
+    entries: VecDeque::new(),
+    dirty: false,
+    enabled: false,
+    hidden: false,
+    revision: 0,
+});
+
+struct Console {
+    entries: VecDeque<LogEntry>,
+    dirty: bool,
+    enabled: bool,
+    hidden: bool,
+
+    /// Counts records kept, so other views of them know when to redraw.
+    revision: u32,
+}
+
+/// Starts showing warnings and errors on the brain's display.
+///
+/// The display is shared so other code can still draw to it. The console redraws over whatever
+/// is on screen the next time a warning or error is logged, or after [`redraw`] is called.
+pub fn enable(display: Rc<RefCell<Display>>) {
+    let Some(mut console) = CONSOLE.try_lock() else {
+        return;
+    };
+
+    if console.enabled {
+        return;
+    }
+
+    console.enabled = true;
+    console.dirty = true;
+    drop(console);
+
+    spawn(async move {
+        loop {
+            sleep(REDRAW_INTERVAL).await;
+
+            let Some(mut console) = CONSOLE.try_lock() else {
+                continue;
+            };
+
+            if !console.dirty || console.hidden {
+                continue;
+            }
+
+            // Skip this redraw if someone else is drawing, and try again next time.
+            if let Ok(mut display) = display.try_borrow_mut() {
+                draw(&mut display, &console.entries);
+                console.dirty = false;
+            }
+        }
+    })
+    .detach();
+}
+
+/// Marks the console to be drawn again, such as after something else used the display.
+pub fn redraw() {
+    if let Some(mut console) = CONSOLE.try_lock() {
+        console.dirty = true;
+    }
+}
+
+/// Stops the console from drawing over the display (such as while a menu is open) until
+/// [`show`] is called. Records are still kept while it's hidden.
+pub fn hide() {
+    if let Some(mut console) = CONSOLE.try_lock() {
+        console.hidden = true;
+    }
+}
+
+/// Lets the console draw to the display again after [`hide`].
+pub fn show() {
+    if let Some(mut console) = CONSOLE.try_lock() {
+        console.hidden = false;
+        console.dirty = true;
+    }
+}
+
+/// Returns a number that changes whenever a record is kept.
+pub(crate) fn revision() -> u32 {
+    CONSOLE.try_lock().map_or(0, |console| console.revision)
+}
+
+/// Returns the `count` most recent records as lines of at most `width` characters, oldest first,
+/// with the color each is drawn in. This is for showing warnings elsewhere on the display while
+/// the console is hidden, such as beside the [field map](crate::field_map).
+pub(crate) fn recent_lines(count: usize, width: usize) -> Vec<(String, Rgb<u8>)> {
+    let Some(console) = CONSOLE.try_lock() else {
+        return Vec::new();
+    };
+
+    console
+        .entries
+        .iter()
+        .skip(console.entries.len().saturating_sub(count))
+        .map(|entry| (truncate(&format_entry(entry), width), color(entry.level)))
+        .collect()
+}
+
+/// Keeps a record to show on the display if it's a warning or error.
+pub(crate) fn write(entry: &LogEntry) {
+    if entry.level > Level::Warn {
+        return;
+    }
+
+    let Some(mut console) = CONSOLE.try_lock() else {
+        return;
+    };
+
+    if !console.enabled {
+        return;
+    }
+
+    if console.entries.len() >= CAPACITY {
+        console.entries.pop_front();
+    }
+    console.entries.push_back(entry.clone());
+    console.dirty = true;
+    console.revision = console.revision.wrapping_add(1);
+}
+
+fn draw(display: &mut Display, entries: &VecDeque<LogEntry>) {
+    let font = Font::new(FontSize::SMALL, FontFamily::Monospace);
+
+    display.fill(
+        &Rect::new(
+            [0, 0],
+            [Display::HORIZONTAL_RESOLUTION, Display::VERTICAL_RESOLUTION],
+        ),
+        Rgb::new(0, 0, 0),
+    );
+    display.fill(
+        &Rect::new([0, 0], [Display::HORIZONTAL_RESOLUTION, HEADER_HEIGHT]),
+        WAR_EAGLE_BLUE,
+    );
+    display.draw_text(
+        &Text::new("Warnings & Errors", font, [MARGIN, 2]),
+        WAR_EAGLE_TEXT,
+        None,
+    );
+
+    // Newest record at the bottom, like a terminal.
+    for (row, entry) in (0..).zip(entries) {
+        display.draw_text(
+            &Text::new(
+                &truncate(&format_entry(entry), LINE_WIDTH),
+                font,
+                [MARGIN, HEADER_HEIGHT + 4 + row * LINE_HEIGHT],
+            ),
+            color(entry.level),
+            None,
+        );
+    }
+}
+
+fn format_entry(entry: &LogEntry) -> String {
+    format!(
+        "{:02}:{:02} {:<5} {}",
+        entry.timestamp.as_secs() / 60,
+        entry.timestamp.as_secs() % 60,
+        entry.level,
+        entry.message
+    )
+}
+
+const fn color(level: Level) -> Rgb<u8> {
+    match level {
+        Level::Error => WAR_EAGLE_ORANGE,
+        _ => WAR_EAGLE_TEXT,
+    }
+}
+
+/// Cuts a line down to at most `width` characters, marking it with `~` if anything was removed.
+fn truncate(line: &str, width: usize) -> String {
+    if line.chars().count() <= width {
+        return String::from(line);
+    }
+
+    let mut truncated: String = line.chars().take(width - 1).collect();
+    truncated.push('~');
+    truncated
+}
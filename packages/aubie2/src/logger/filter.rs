@@ -0,0 +1,145 @@
+//! Per-Module Log Filtering
+//!
+//! Filters are written in the same style as `env_logger`'s `RUST_LOG` variable: a comma-separated
+//! list of `module=level` directives, with an optional bare `level` setting the default for
+//! everything else. For example, `info,aubie2::subsystems::intake=debug,evian=warn`.
+//!
+//! A module directive applies to that module and all of its children, and the longest matching
+//! directive wins. A bare module name with no level enables all levels for it.
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{fmt, str::FromStr};
+
+use log::{Level, LevelFilter};
+
+/// A parsed log filter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogFilter {
+    default: LevelFilter,
+    directives: Vec<Directive>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Directive {
+    module: String,
+    level: LevelFilter,
+}
+
+impl LogFilter {
+    /// A filter that allows every record at or above the given level.
+    pub const fn new(level: LevelFilter) -> Self {
+        Self {
+            default: level,
+            directives: Vec::new(),
+        }
+    }
+
+    /// Parses a filter from an `env_logger`-style spec.
+    pub fn parse(spec: &str) -> Result<Self, ParseFilterError> {
+        let mut filter = Self::new(LevelFilter::Error);
+
+        for directive in spec.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+            match directive.split_once('=') {
+                Some((module, level)) => {
+                    let module = module.trim();
+
+                    if module.is_empty() {
+                        return Err(ParseFilterError::EmptyModule);
+                    }
+
+                    filter.set_module(module, parse_level(level.trim())?);
+                }
+                None => match parse_level(directive) {
+                    Ok(level) => filter.default = level,
+                    Err(_) => filter.set_module(directive, LevelFilter::Trace),
+                },
+            }
+        }
+
+        Ok(filter)
+    }
+
+    /// Sets the level for a module and its children, replacing any existing directive for it.
+    pub fn set_module(&mut self, module: &str, level: LevelFilter) {
+        match self.directives.iter_mut().find(|d| d.module == module) {
+            Some(directive) => directive.level = level,
+            None => self.directives.push(Directive {
+                module: module.to_string(),
+                level,
+            }),
+        }
+    }
+
+    /// Returns the most verbose level any record can pass this filter at.
+    pub fn max_level(&self) -> LevelFilter {
+        self.directives
+            .iter()
+            .map(|d| d.level)
+            .fold(self.default, Ord::max)
+    }
+
+    /// Returns `true` if a record from `target` at `level` passes this filter.
+    pub fn enabled(&self, target: &str, level: Level) -> bool {
+        level <= self.level_for(target)
+    }
+
+    fn level_for(&self, target: &str) -> LevelFilter {
+        self.directives
+            .iter()
+            .filter(|d| is_module_or_child(target, &d.module))
+            .max_by_key(|d| d.module.len())
+            .map_or(self.default, |d| d.level)
+    }
+}
+
+impl FromStr for LogFilter {
+    type Err = ParseFilterError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl fmt::Display for LogFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.default)?;
+
+        for directive in &self.directives {
+            write!(f, ",{}={}", directive.module, directive.level)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Error returned when a filter spec is malformed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseFilterError {
+    /// A `module=level` directive had nothing before the `=`.
+    EmptyModule,
+
+    /// A level wasn't one of `off`, `error`, `warn`, `info`, `debug` or `trace`.
+    InvalidLevel(String),
+}
+
+impl fmt::Display for ParseFilterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EmptyModule => write!(f, "directive is missing a module name"),
+            Self::InvalidLevel(level) => write!(f, "invalid log level `{level}`"),
+        }
+    }
+}
+
+fn parse_level(level: &str) -> Result<LevelFilter, ParseFilterError> {
+    LevelFilter::from_str(level).map_err(|_| ParseFilterError::InvalidLevel(level.to_string()))
+}
+
+fn is_module_or_child(target: &str, module: &str) -> bool {
+    target
+        .strip_prefix(module)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+}
@@ -0,0 +1,324 @@
+//! Kernel Logger Implementation
+//!
+//! Logging never performs I/O on the calling task. Records are formatted into a bounded queue
+//! and written out to serial (and any other enabled sinks, such as the SD card, the brain's
+//! display or the controller's screen) by a background drain task that only handles a small
+//! batch at a time before yielding, so a burst of logs from a control loop can't stall it. If
+//! the queue fills up faster than it can be drained, or is locked by another task, new records
+//! are dropped and the number of dropped records is reported by the drain task.
+
+use alloc::{
+    collections::VecDeque,
+    format,
+    string::{String, ToString},
+};
+use core::{
+    fmt, mem,
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+
+use log::{
+    info, max_level, set_logger, set_max_level, warn, Level, LevelFilter, Log, Metadata, Record,
+    SetLoggerError,
+};
+use vexide::{sync::Mutex, task::spawn, time::sleep};
+
+use crate::telemetry;
+
+pub mod alerts;
+pub mod console;
+mod filter;
+pub mod sd_card;
+
+pub use filter::{LogFilter, ParseFilterError};
+
+const ESCAPES: [Option<&str>; 6] = [
+    None,             // Default foreground
+    Some("\x1B[31m"), // Error (red)
+    Some("\x1B[33m"), // Warn (yellow)
+    Some("\x1B[34m"), // Info (blue)
+    Some("\x1B[36m"), // Debug (cyan)
+    Some("\x1B[37m"), // Trace (white)
+];
+
+/// Maximum number of records waiting to be written.
+pub const QUEUE_CAPACITY: usize = 128;
+
+/// Maximum number of records written per drain before yielding to other tasks.
+const DRAIN_BATCH_SIZE: usize = 16;
+
+/// How long the drain task sleeps between batches.
+const DRAIN_INTERVAL: Duration = Duration::from_millis(10);
+
+/// How often the serial port is checked for commands.
+const COMMAND_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+static FILTER: Mutex<LogFilter> = Mutex::new(LogFilter::new(LevelFilter::Off));
+
+static QUEUE: Mutex<LogQueue> = Mutex::new(LogQueue {
+    entries: VecDeque::new(),
+    unreported_drops: 0,
+    total_drops: 0,
+});
+
+/// Records dropped because [`QUEUE`] was locked, counted outside it since it couldn't be locked to
+/// count them. The drain task moves these into the queue's counts.
+static BUSY_DROPS: AtomicUsize = AtomicUsize::new(0);
+
+struct LogQueue {
+    entries: VecDeque<LogEntry>,
+    unreported_drops: usize,
+    total_drops: usize,
+}
+
+/// A formatted log record waiting to be written to the logger's sinks.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogEntry {
+    /// Time since program start.
+    pub timestamp: Duration,
+    pub level: Level,
+    pub target: String,
+    pub message: String,
+}
+
+impl LogEntry {
+    fn from_record(record: &Record<'_>) -> Self {
+        let args = record.args();
+
+        Self {
+            timestamp: Duration::from_micros(unsafe { vex_sdk::vexSystemHighResTimeGet() }),
+            level: record.level(),
+            target: record.target().to_string(),
+            message: args
+                .as_str()
+                .map_or_else(|| args.to_string(), ToString::to_string),
+        }
+    }
+}
+
+pub struct SerialLogger;
+
+impl SerialLogger {
+    /// Installs the logger with the given `env_logger`-style filter spec (see [`LogFilter`]),
+    /// optionally also writing records to the brain's SD card.
+    ///
+    /// The filter can later be changed with [`SerialLogger::set_filter`], or by sending
+    /// `log <spec>` over serial.
+    pub fn init(&'static self, filter: &str, sd_card: bool) -> Result<(), InitError> {
+        let filter = LogFilter::parse(filter).map_err(InitError::Filter)?;
+
+        set_logger(self).map_err(InitError::SetLogger)?;
+        self.apply_filter(filter);
+
+        spawn(async {
+            loop {
+                while drain(DRAIN_BATCH_SIZE) == DRAIN_BATCH_SIZE {
+                    sleep(Duration::ZERO).await;
+                }
+                telemetry::drain();
+
+                sleep(DRAIN_INTERVAL).await;
+            }
+        })
+        .detach();
+
+        spawn(handle_serial_commands()).detach();
+
+        if sd_card && !sd_card::enable() {
+            warn!("No SD card inserted, logs will not be saved.");
+        }
+
+        Ok(())
+    }
+
+    /// Replaces the active filter with one parsed from an `env_logger`-style spec.
+    pub fn set_filter(&self, spec: &str) -> Result<(), ParseFilterError> {
+        self.apply_filter(LogFilter::parse(spec)?);
+
+        Ok(())
+    }
+
+    /// Returns a copy of the active filter.
+    pub fn filter(&self) -> LogFilter {
+        FILTER
+            .try_lock()
+            .map_or_else(|| LogFilter::new(max_level()), |filter| filter.clone())
+    }
+
+    fn apply_filter(&self, filter: LogFilter) {
+        set_max_level(filter.max_level());
+
+        if let Some(mut active) = FILTER.try_lock() {
+            *active = filter;
+        }
+    }
+
+    /// Returns the number of records dropped so far because the queue was full or busy.
+    pub fn dropped(&self) -> usize {
+        QUEUE.try_lock().map_or(0, |queue| queue.total_drops) + BUSY_DROPS.load(Ordering::Relaxed)
+    }
+}
+
+/// Error returned by [`SerialLogger::init`].
+#[derive(Debug)]
+pub enum InitError {
+    /// Another logger was already installed.
+    SetLogger(SetLoggerError),
+
+    /// The filter spec couldn't be parsed.
+    Filter(ParseFilterError),
+}
+
+impl fmt::Display for InitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::SetLogger(err) => write!(f, "{err}"),
+            Self::Filter(err) => write!(f, "invalid log filter: {err}"),
+        }
+    }
+}
+
+impl Log for SerialLogger {
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        // The filter is only ever locked briefly, so if it's busy just fall back to the global
+        // level rather than losing the record.
+        metadata.level() <= max_level()
+            && FILTER.try_lock().map_or(true, |filter| {
+                filter.enabled(metadata.target(), metadata.level())
+            })
+    }
+
+    fn log(&self, record: &Record<'_>) {
+        if self.enabled(record.metadata()) {
+            let Some(mut queue) = QUEUE.try_lock() else {
+                BUSY_DROPS.fetch_add(1, Ordering::Relaxed);
+                return;
+            };
+
+            if queue.entries.len() >= QUEUE_CAPACITY {
+                queue.unreported_drops += 1;
+                queue.total_drops += 1;
+                return;
+            }
+
+            queue.entries.push_back(LogEntry::from_record(record));
+        }
+    }
+
+    fn flush(&self) {
+        while drain(usize::MAX) != 0 {}
+        telemetry::drain();
+        sd_card::flush();
+    }
+}
+
+/// Writes up to `limit` queued records to every sink, returning how many were written.
+fn drain(limit: usize) -> usize {
+    let mut written = 0;
+
+    while written < limit {
+        // Only hold the lock long enough to pop, so records logged while writing aren't lost.
+        let Some(entry) = QUEUE
+            .try_lock()
+            .and_then(|mut queue| queue.entries.pop_front())
+        else {
+            break;
+        };
+
+        write_entry(&entry);
+        written += 1;
+    }
+
+    let drops = QUEUE.try_lock().map_or(0, |mut queue| {
+        let busy = BUSY_DROPS.swap(0, Ordering::Relaxed);
+        queue.total_drops += busy;
+        queue.unreported_drops += busy;
+
+        mem::take(&mut queue.unreported_drops)
+    });
+
+    if drops > 0 {
+        write_entry(&LogEntry {
+            timestamp: Duration::from_micros(unsafe { vex_sdk::vexSystemHighResTimeGet() }),
+            level: Level::Warn,
+            target: module_path!().to_string(),
+            message: format!("{} records dropped, log queue was full.", drops),
+        });
+    }
+
+    written
+}
+
+/// Reads commands from serial:
+///
+/// - `log <spec>` changes the filter at runtime, and a bare `log` prints the active filter.
+/// - `telemetry on` and `telemetry off` toggle the binary telemetry stream.
+async fn handle_serial_commands() {
+    let mut line = String::new();
+
+    loop {
+        loop {
+            let byte = unsafe { vex_sdk::vexSerialReadChar(1) };
+
+            if byte < 0 {
+                break;
+            }
+
+            match byte as u8 {
+                b'\r' | b'\n' => {
+                    run_command(line.trim());
+                    line.clear();
+                }
+                byte => line.push(byte as char),
+            }
+        }
+
+        sleep(COMMAND_POLL_INTERVAL).await;
+    }
+}
+
+fn run_command(command: &str) {
+    let (name, args) = command.split_once(' ').unwrap_or((command, ""));
+    let args = args.trim();
+
+    match (name, args) {
+        ("log", "") => info!("Log filter is `{}`.", SerialLogger.filter()),
+        ("log", spec) => match SerialLogger.set_filter(spec) {
+            Ok(()) => info!("Log filter set to `{}`.", SerialLogger.filter()),
+            Err(err) => warn!("Couldn't set log filter: {}", err),
+        },
+        ("telemetry", "on") => {
+            telemetry::enable();
+            info!("Telemetry enabled.");
+        }
+        ("telemetry", "off") => {
+            telemetry::disable();
+            info!("Telemetry disabled.");
+        }
+        _ => {}
+    }
+}
+
+fn write_entry(entry: &LogEntry) {
+    write_serial(entry);
+    sd_card::write(entry);
+    console::write(entry);
+    alerts::write(entry);
+}
+
+fn write_serial(entry: &LogEntry) {
+    let mins = entry.timestamp.as_secs() / 60;
+    let submin_secs = entry.timestamp.as_secs() % 60;
+
+    vexide::io::println!(
+        "{:02}:{:02}:{:02} {}[{} {}]\x1B[0m {}",
+        mins,
+        submin_secs,
+        entry.timestamp.subsec_millis(),
+        ESCAPES[entry.level as usize].unwrap_or_default(),
+        entry.level,
+        entry.target,
+        entry.message
+    );
+}
@@ -0,0 +1,212 @@
+//! SD Card Log Sink
+//!
+//! Records are buffered in RAM and periodically appended to a log file on the brain's SD card by
+//! a background task. Writing a record only appends to the buffer, so logging never blocks on the
+//! card. Each run gets its own set of files named after the brain's clock, and a new
+//! file is started whenever the current one exceeds [`MAX_FILE_SIZE`].
+//!
+//! Telemetry frames are saved alongside the text records, the same way they're interleaved on
+//! serial, so a log file can be read by the same host tools as a serial capture.
+
+use alloc::{format, string::String, vec::Vec};
+use core::{
+    mem,
+    sync::atomic::{AtomicBool, Ordering},
+    time::Duration,
+};
+
+use vexide::{
+    fs::OpenOptions,
+    io::Write,
+    sync::Mutex,
+    task::spawn,
+    time::{sleep, Instant},
+};
+
+use super::LogEntry;
+
+/// How often buffered records are written to the SD card.
+const FLUSH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Buffered bytes that trigger an early flush.
+const FLUSH_THRESHOLD: usize = 4 * 1024;
+
+/// How often the background task checks whether the buffer has passed [`FLUSH_THRESHOLD`].
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Buffered bytes past which new records are dropped (e.g. if the card was pulled mid-match).
+const MAX_BUFFER_SIZE: usize = 32 * 1024;
+
+/// Size at which a log file is rotated.
+pub const MAX_FILE_SIZE: usize = 512 * 1024;
+
+static SD_CARD: Mutex<Option<SdCardLog>> = Mutex::new(None);
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+struct SdCardLog {
+    buffer: Vec<u8>,
+    file_stem: String,
+    part: u32,
+    file_size: usize,
+    dropped: usize,
+}
+
+impl SdCardLog {
+    fn path(&self) -> String {
+        format!("{}_{}.log", self.file_stem, self.part)
+    }
+}
+
+/// Returns `true` if an SD card is inserted in the brain.
+pub fn is_inserted() -> bool {
+    unsafe { vex_sdk::vexFileDriveStatus(0) }
+}
+
+/// Starts logging to the SD card.
+///
+/// Returns `false` if no card is inserted.
+pub(crate) fn enable() -> bool {
+    if !is_inserted() {
+        return false;
+    }
+
+    let Some(mut sd_card) = SD_CARD.try_lock() else {
+        return false;
+    };
+
+    if sd_card.is_some() {
+        return true;
+    }
+
+    *sd_card = Some(SdCardLog {
+        buffer: Vec::new(),
+        file_stem: file_stem(),
+        part: 0,
+        file_size: 0,
+        dropped: 0,
+    });
+    drop(sd_card);
+    ENABLED.store(true, Ordering::Release);
+
+    spawn(async {
+        let mut last_flush = Instant::now();
+
+        loop {
+            sleep(POLL_INTERVAL).await;
+
+            if last_flush.elapsed() >= FLUSH_INTERVAL || buffered() >= FLUSH_THRESHOLD {
+                flush();
+                last_flush = Instant::now();
+            }
+        }
+    })
+    .detach();
+
+    true
+}
+
+/// Returns `true` if logging to the SD card has been started.
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Acquire)
+}
+
+/// Buffers a record to be written on the next flush.
+pub(crate) fn write(entry: &LogEntry) {
+    let line = format!(
+        "{:02}:{:02}:{:02} [{} {}] {}\n",
+        entry.timestamp.as_secs() / 60,
+        entry.timestamp.as_secs() % 60,
+        entry.timestamp.subsec_millis(),
+        entry.level,
+        entry.target,
+        entry.message
+    );
+
+    write_bytes(line.as_bytes());
+}
+
+/// Buffers an encoded telemetry frame to be written on the next flush.
+pub(crate) fn write_frame(frame: &[u8]) {
+    write_bytes(frame);
+}
+
+/// Appends to the buffer. This never touches the card, so it's safe to call from control loops;
+/// the background task does all of the file I/O.
+fn write_bytes(bytes: &[u8]) {
+    // Writing while a flush is in progress (or with the sink disabled) is a no-op.
+    let Some(mut guard) = SD_CARD.try_lock() else {
+        return;
+    };
+    let Some(sd_card) = guard.as_mut() else {
+        return;
+    };
+
+    if sd_card.buffer.len() >= MAX_BUFFER_SIZE {
+        sd_card.dropped += 1;
+        return;
+    }
+
+    sd_card.buffer.extend_from_slice(bytes);
+}
+
+/// Returns the number of buffered bytes waiting to be written.
+fn buffered() -> usize {
+    SD_CARD
+        .try_lock()
+        .and_then(|sd_card| sd_card.as_ref().map(|sd_card| sd_card.buffer.len()))
+        .unwrap_or(0)
+}
+
+/// Writes all buffered records to the SD card.
+///
+/// This blocks on the card, so it's only called by the background task and when the logger is
+/// explicitly flushed.
+pub fn flush() {
+    let Some(mut guard) = SD_CARD.try_lock() else {
+        return;
+    };
+    let Some(sd_card) = guard.as_mut() else {
+        return;
+    };
+
+    if sd_card.dropped > 0 {
+        let dropped = mem::take(&mut sd_card.dropped);
+        sd_card
+            .buffer
+            .extend_from_slice(format!("[{} records dropped]\n", dropped).as_bytes());
+    }
+
+    if sd_card.buffer.is_empty() {
+        return;
+    }
+
+    let Ok(mut file) = OpenOptions::new()
+        .append(true)
+        .create(true)
+        .open(sd_card.path().as_str())
+    else {
+        // Keep the buffer around in case the card comes back.
+        return;
+    };
+
+    if file.write_all(&sd_card.buffer).is_ok() {
+        sd_card.file_size += sd_card.buffer.len();
+        sd_card.buffer.clear();
+    }
+    _ = file.flush();
+
+    if sd_card.file_size >= MAX_FILE_SIZE {
+        sd_card.part += 1;
+        sd_card.file_size = 0;
+    }
+}
+
+/// Builds a `aubie2_YYYYMMDD_HHMMSS` file name from the brain's clock.
+fn file_stem() -> String {
+    let (date, time) = unsafe { (vex_sdk::vexGetdate(), vex_sdk::vexGettime()) };
+
+    format!(
+        "aubie2_{:04}{:02}{:02}_{:02}{:02}{:02}",
+        date.da_year, date.da_mon, date.da_day, time.ti_hour, time.ti_min, time.ti_sec
+    )
+}
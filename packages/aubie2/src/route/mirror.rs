@@ -0,0 +1,77 @@
+use core::f64::consts::PI;
+
+use evian::math::{Angle, Vec2};
+
+use super::{Alliance, StartPose};
+
+/// Reflection across the field's vertical axis, for running a route written for one alliance
+/// on the other.
+///
+/// Routes that support mirroring take a `Mirror` and pass their points, headings and
+/// side-specific mechanisms through it:
+///
+/// ```ignore
+/// seeking.move_to_point(dt, mirror.point((26.0, -53.0))).await;
+/// basic.turn_to_heading(dt, mirror.heading(70.0.deg())).await;
+/// _ = mirror.side(&mut self.left_arm, &mut self.right_arm).set_high();
+/// ```
+///
+/// Distances are unchanged, since driving forward is still driving forward.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Mirror {
+    /// Run the route as written.
+    #[default]
+    Identity,
+
+    /// Run the route reflected across the field's vertical axis, for the other alliance.
+    Flipped,
+}
+
+impl Mirror {
+    pub const fn is_flipped(self) -> bool {
+        matches!(self, Self::Flipped)
+    }
+
+    /// Mirrors a point, negating its x coordinate.
+    pub fn point(self, point: impl Into<Vec2<f64>>) -> Vec2<f64> {
+        let point = point.into();
+
+        match self {
+            Self::Identity => point,
+            Self::Flipped => Vec2::new(-point.x, point.y),
+        }
+    }
+
+    /// Mirrors a heading, reflecting it across the vertical axis (so 0° becomes 180°).
+    pub fn heading(self, heading: Angle) -> Angle {
+        match self {
+            Self::Identity => heading,
+            Self::Flipped => Angle::from_radians(PI - heading.as_radians()),
+        }
+    }
+
+    /// Picks between a side-specific mechanism the route was written for and its counterpart on
+    /// the other side of the robot, such as the left and right goal rush arms.
+    pub fn side<T>(self, written: T, mirrored: T) -> T {
+        match self {
+            Self::Identity => written,
+            Self::Flipped => mirrored,
+        }
+    }
+
+    pub const fn alliance(self, alliance: Alliance) -> Alliance {
+        match (self, alliance) {
+            (Self::Identity, alliance) => alliance,
+            (Self::Flipped, Alliance::Red) => Alliance::Blue,
+            (Self::Flipped, Alliance::Blue) => Alliance::Red,
+        }
+    }
+
+    /// Builds a mirrored [`StartPose`] from a position in inches and heading in degrees.
+    pub const fn start_pose(self, x: f64, y: f64, heading: f64) -> StartPose {
+        match self {
+            Self::Identity => StartPose::from_degrees(x, y, heading),
+            Self::Flipped => StartPose::from_degrees(-x, y, 180.0 - heading),
+        }
+    }
+}
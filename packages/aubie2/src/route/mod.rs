@@ -0,0 +1,374 @@
+//! Autonomous Routes
+//!
+//! A [`Route`] describes an autonomous routine: its name, which alliance it's for, where the
+//! robot starts, and the async body that drives it. Each robot binary lists its routes with the
+//! [`routes!`](crate::routes) macro, and anything that needs to know about them (the selector,
+//! logs, simulations) works from that list rather than calling route functions directly.
+//!
+//! Routes written against a [`Mirror`] can be listed once and run on either alliance, and
+//! [`Triggers`] fire subsystem actions partway through a motion based on the robot's progress.
+//! A [`Timeline`] records how long each step of a route took and how far the robot drifted from
+//! where the route expected it to be.
+//!
+//! # Field Frame
+//!
+//! Every route's points, headings and [`StartPose`] are in the same frame, measured from the
+//! alliance the route is written for:
+//!
+//! - The origin is the center of the field, with distances in inches.
+//! - +y points away from the route's own alliance wall, so that wall is at y = -72 and the
+//!   alliance stake is near (0, -70).
+//! - +x points to the right, as seen from behind the alliance wall.
+//! - Headings are counterclockwise from +x, so 90° faces away from the alliance wall.
+//!
+//! Since the frame is relative to the alliance, a route's [`Mirror`] only has to reflect it
+//! across x = 0 to run it from the other side. Tracking starts at 90°, so the IMU should be
+//! calibrated with the robot facing away from the alliance wall. While disabled, the selector
+//! compares that heading with the selected route's [`StartPose`] and warns on the controller if
+//! the robot is placed wrong.
+
+mod mirror;
+pub mod script;
+mod timeline;
+mod triggers;
+
+use alloc::{boxed::Box, string::String, vec::Vec};
+use core::{future::Future, pin::Pin, time::Duration};
+
+use evian::{
+    math::{Angle, Vec2},
+    tracking::TracksHeading,
+};
+use vexide::prelude::Rgb;
+
+pub use self::{
+    mirror::Mirror,
+    timeline::{Checkpoint, Outcome, StepFailed, Timeline, TimelineStep, ACTION_PREFIX},
+    triggers::{Trigger, Triggers},
+};
+use crate::tracking::{wrap, SharedTracking};
+
+/// Length of the autonomous period in a match.
+pub const MATCH_PERIOD: Duration = Duration::from_secs(15);
+
+/// Length of a programming skills run.
+pub const SKILLS_PERIOD: Duration = Duration::from_secs(60);
+
+/// The future returned by [`Route::run`].
+pub type RouteFuture<'a> = Pin<Box<dyn Future<Output = ()> + 'a>>;
+
+/// The alliance a route is meant to be run on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Alliance {
+    Red,
+    Blue,
+}
+
+impl Alliance {
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Red => "Red",
+            Self::Blue => "Blue",
+        }
+    }
+
+    pub const fn color(self) -> Rgb<u8> {
+        match self {
+            Self::Red => Rgb::new(220, 40, 40),
+            Self::Blue => Rgb::new(40, 90, 220),
+        }
+    }
+}
+
+/// Where the robot is placed at the start of a route, in the [field frame](self#field-frame).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StartPose {
+    pub position: Vec2<f64>,
+    pub heading: Angle,
+}
+
+impl StartPose {
+    pub const fn new(x: f64, y: f64, heading: Angle) -> Self {
+        Self {
+            position: Vec2::new(x, y),
+            heading,
+        }
+    }
+
+    /// Like [`StartPose::new`], with the heading in degrees.
+    pub const fn from_degrees(x: f64, y: f64, heading: f64) -> Self {
+        Self::new(x, y, Angle::from_degrees(heading))
+    }
+
+    /// Resets tracking to this pose.
+    pub fn apply(&self, tracking: &SharedTracking) {
+        tracking.set_position(self.position);
+        tracking.set_heading(self.heading);
+    }
+
+    /// How far `tracking`'s heading is from this pose's, wrapped to ±180°. Positive errors mean
+    /// the robot is turned counterclockwise of where it should be.
+    pub fn heading_error(&self, tracking: &SharedTracking) -> Angle {
+        Angle::from_radians(wrap(
+            tracking.heading().as_radians() - self.heading.as_radians(),
+        ))
+    }
+}
+
+/// An autonomous routine for a robot of type `R`.
+pub trait Route<R> {
+    /// Name shown in the selector and logs. Routes are looked up by this, so it should be unique
+    /// within a robot's route list.
+    fn name(&self) -> &'static str;
+
+    fn alliance(&self) -> Alliance;
+
+    /// Short summary of what the route does, shown in the selector.
+    fn description(&self) -> &'static str {
+        ""
+    }
+
+    /// Where the robot is placed for the route, or `None` if that isn't known, such as for a
+    /// [script](script::script_start) route whose script can't be loaded.
+    fn start(&self) -> Option<StartPose>;
+
+    /// How long the route has to finish, such as [`MATCH_PERIOD`] or [`SKILLS_PERIOD`].
+    fn period(&self) -> Duration {
+        MATCH_PERIOD
+    }
+
+    /// Points the route drives through, in order and in the [field frame](self#field-frame).
+    /// These are only drawn on the [field map](crate::field_map), so routes without them still
+    /// run the same.
+    fn waypoints(&self) -> Vec<Vec2<f64>> {
+        Vec::new()
+    }
+
+    /// Checks anything the route needs that isn't compiled in, such as a
+    /// [script](script::run_script) on the SD card, returning what's wrong if it can't run. The
+    /// selector runs this when the route is picked.
+    fn check(&self) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// Runs the route's body. Tracking should already be reset to [`Route::start`], if it's known.
+    fn run<'a>(&'a self, robot: &'a mut R) -> RouteFuture<'a>;
+}
+
+/// A route whose body is a plain function, as created by [`routes!`](crate::routes).
+///
+/// The body is passed `mirror` when it runs, so a single function can back both a route and its
+/// mirrored copy.
+pub struct FnRoute<R: 'static> {
+    pub name: &'static str,
+    pub alliance: Alliance,
+    pub description: &'static str,
+    /// Returns where the robot is placed, mirrored the same way as the body.
+    pub start: fn(Mirror) -> Option<StartPose>,
+    pub period: Duration,
+
+    /// Returns the points the body drives through, mirrored the same way as the body.
+    pub waypoints: Option<fn(Mirror) -> Vec<Vec2<f64>>>,
+    pub check: Option<fn() -> Result<(), String>>,
+    pub mirror: Mirror,
+    pub body: for<'a> fn(&'a mut R, Mirror) -> RouteFuture<'a>,
+}
+
+impl<R> Route<R> for FnRoute<R> {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn alliance(&self) -> Alliance {
+        self.alliance
+    }
+
+    fn description(&self) -> &'static str {
+        self.description
+    }
+
+    fn start(&self) -> Option<StartPose> {
+        (self.start)(self.mirror)
+    }
+
+    fn period(&self) -> Duration {
+        self.period
+    }
+
+    fn waypoints(&self) -> Vec<Vec2<f64>> {
+        self.waypoints
+            .map_or_else(Vec::new, |waypoints| waypoints(self.mirror))
+    }
+
+    fn check(&self) -> Result<(), String> {
+        self.check.map_or(Ok(()), |check| check())
+    }
+
+    fn run<'a>(&'a self, robot: &'a mut R) -> RouteFuture<'a> {
+        (self.body)(robot, self.mirror)
+    }
+}
+
+/// Boxes a route body into a [`RouteFuture`].
+pub fn boxed<'a>(body: impl Future<Output = ()> + 'a) -> RouteFuture<'a> {
+    Box::pin(body)
+}
+
+/// Finds a route in a list by name.
+pub fn find<R>(routes: &[&'static dyn Route<R>], name: &str) -> Option<&'static dyn Route<R>> {
+    routes.iter().copied().find(|route| route.name() == name)
+}
+
+/// Builds a robot's route list from its route functions.
+///
+/// ```ignore
+/// pub const ROUTES: &[&dyn Route<Robot>] = routes![Robot;
+///     "Red Safe" {
+///         alliance: Red,
+///         description: "Alliance stake, goal, three stacks",
+///         start: (10.0, -63.0, 214.0),
+///         waypoints: Robot::safe_waypoints,
+///         run: Robot::safe,
+///         mirrored: "Blue Safe",
+///     },
+///     "Skills" {
+///         alliance: Red,
+///         description: "Programming skills",
+///         start: (0.0, -62.0, 270.0),
+///         period: 60,
+///         run: Robot::skills,
+///     },
+/// ];
+/// ```
+///
+/// `start` is the starting position in inches and heading in degrees, in the
+/// [field frame](crate::route#field-frame). Routes that only know where they start once they're
+/// loaded, such as [script](script::script_start) routes, use `start_from` instead, with an
+/// `fn(Mirror) -> Option<StartPose>`. `period` is an optional time limit in seconds
+/// (defaulting to [`MATCH_PERIOD`]), and `waypoints` is an optional `fn() -> Vec<Vec2<f64>>`
+/// returning the points the route drives through for the [field map](crate::field_map), which
+/// should come from the same constants as the body. `check` is an optional
+/// `fn() -> Result<(), String>` run as [`Route::check`]. `run` is an `async fn(&mut Robot)`. Routes
+/// with a `mirrored` name are also listed under that name for the other alliance, and `run` and
+/// `waypoints` instead take a [`Mirror`] as well.
+#[macro_export]
+macro_rules! routes {
+    // A route that's also listed mirrored for the other alliance.
+    (
+        @munch $robot:ty; [$($out:expr,)*];
+        $name:literal {
+            alliance: $alliance:ident,
+            description: $description:literal,
+            $(start: ($x:expr, $y:expr, $heading:expr),)?
+            $(start_from: $start_from:path,)?
+            $(period: $period:expr,)?
+            $(waypoints: $waypoints:path,)?
+            $(check: $check:path,)?
+            run: $run:path,
+            mirrored: $mirrored:literal $(,)?
+        }
+        $(, $($rest:tt)*)?
+    ) => {
+        $crate::routes!(@munch $robot; [
+            $($out,)*
+            $crate::routes!(@route $robot; $name, $alliance, $description, [$(($x, $y, $heading))?], [$($start_from)?], [$($period)?], [$($waypoints)?], [$($check)?], Identity, {
+                fn body(robot: &mut $robot, mirror: $crate::route::Mirror) -> $crate::route::RouteFuture<'_> {
+                    $crate::route::boxed($run(robot, mirror))
+                }
+                body
+            }),
+            $crate::routes!(@route $robot; $mirrored, $alliance, $description, [$(($x, $y, $heading))?], [$($start_from)?], [$($period)?], [$($waypoints)?], [$($check)?], Flipped, {
+                fn body(robot: &mut $robot, mirror: $crate::route::Mirror) -> $crate::route::RouteFuture<'_> {
+                    $crate::route::boxed($run(robot, mirror))
+                }
+                body
+            }),
+        ]; $($($rest)*)?)
+    };
+
+    (
+        @munch $robot:ty; [$($out:expr,)*];
+        $name:literal {
+            alliance: $alliance:ident,
+            description: $description:literal,
+            $(start: ($x:expr, $y:expr, $heading:expr),)?
+            $(start_from: $start_from:path,)?
+            $(period: $period:expr,)?
+            $(waypoints: $waypoints:path,)?
+            $(check: $check:path,)?
+            run: $run:path $(,)?
+        }
+        $(, $($rest:tt)*)?
+    ) => {
+        $crate::routes!(@munch $robot; [
+            $($out,)*
+            $crate::routes!(@route $robot; $name, $alliance, $description, [$(($x, $y, $heading))?], [$($start_from)?], [$($period)?], [$(|_| $waypoints())?], [$($check)?], Identity, {
+                fn body(robot: &mut $robot, _: $crate::route::Mirror) -> $crate::route::RouteFuture<'_> {
+                    $crate::route::boxed($run(robot))
+                }
+                body
+            }),
+        ]; $($($rest)*)?)
+    };
+
+    (@munch $robot:ty; [$($out:expr,)*];) => {
+        &[$($out),*]
+    };
+
+    (
+        @route $robot:ty;
+        $name:literal, $alliance:ident, $description:literal, [$($fixed:tt)?], [$($start_from:path)?],
+        [$($period:expr)?], [$($waypoints:expr)?], [$($check:path)?], $mirror:ident, $body:block
+    ) => {
+        &$crate::route::FnRoute::<$robot> {
+            name: $name,
+            alliance: $crate::route::Mirror::$mirror.alliance($crate::route::Alliance::$alliance),
+            description: $description,
+            start: $crate::routes!(@start $($fixed)? $($start_from)?),
+            period: $crate::routes!(@period $($period)?),
+            waypoints: $crate::routes!(@waypoints $($waypoints)?),
+            check: $crate::routes!(@check $($check)?),
+            mirror: $crate::route::Mirror::$mirror,
+            body: $body,
+        } as &dyn $crate::route::Route<$robot>
+    };
+
+    (@start ($x:expr, $y:expr, $heading:expr)) => {{
+        fn start(mirror: $crate::route::Mirror) -> Option<$crate::route::StartPose> {
+            Some(mirror.start_pose($x, $y, $heading))
+        }
+        start
+    }};
+
+    (@start $start_from:path) => {
+        $start_from
+    };
+
+    (@waypoints) => {
+        None
+    };
+
+    (@waypoints $waypoints:expr) => {
+        Some($waypoints)
+    };
+
+    (@check) => {
+        None
+    };
+
+    (@check $check:path) => {
+        Some($check)
+    };
+
+    (@period) => {
+        $crate::route::MATCH_PERIOD
+    };
+
+    (@period $period:expr) => {
+        ::core::time::Duration::from_secs($period)
+    };
+
+    ($robot:ty; $($routes:tt)*) => {
+        $crate::routes!(@munch $robot; []; $($routes)*)
+    };
+}
@@ -0,0 +1,218 @@
+//! Route Scripts
+//!
+//! A route script describes a route as data rather than code, so it can be edited, diffed and
+//! loaded from the SD card without recompiling. Scripts are plain text with one step per line:
+//!
+//! ```text
+//! # Red safe, alliance stake first.
+//! start 10 -63 214
+//! lady_brown raised
+//! wait 350
+//! intake top 12
+//! wait 950
+//!
+//! move_to 26 -53 reverse
+//! turn 0
+//! drive -26 at 0 limit 4
+//! set clamp high
+//!
+//! parallel
+//!     move_to 41 -53
+//!     sequence
+//!         wait 200
+//!         intake 12
+//!     end
+//! end
+//! await near 56 -21 within 3 timeout 2000
+//! ```
+//!
+//! Distances are in inches, headings in degrees, voltages in volts and times in milliseconds.
+//! Everything after a `#` is a comment. The available steps are:
+//!
+//! | Step | Effect |
+//! | --- | --- |
+//! | `start <x> <y> <heading>` | Resets tracking to a pose. Scripts must start with one. |
+//! | `move_to <x> <y> [reverse] [timeout <ms>] [limit <V>]` | Seeks to a point. |
+//! | `turn <heading> [timeout <ms>]` | Turns to a heading. |
+//! | `drive <distance> [at <heading>] [timeout <ms>] [limit <V>]` | Drives straight. |
+//! | `intake <V>`, `intake top <V>`, `intake bottom <V>`, `intake stop` | Sets intake voltages. |
+//! | `intake raise`, `intake lower` | Moves the intake. |
+//! | `intake jam on\|off`, `intake reject red\|blue\|off` | Configures jam prevention and sorting. |
+//! | `lady_brown <preset>` | Moves the lady brown to one of the robot's named presets. |
+//! | `set <pneumatic> high\|low` | Sets one of the robot's named pneumatics. |
+//! | `wait <ms>` | Waits. |
+//! | `await near <x> <y> within <radius> [timeout <ms>]` | Waits until the robot is near a point. |
+//! | `parallel` ... `end` | Runs each step in the block at the same time. |
+//! | `sequence` ... `end` | Runs steps in order, for use as a branch of a `parallel` block. |
+//!
+//! A script's first step must be `start`, which is also its route's [start
+//! pose](super::Route::start) for checking the robot's placement before the match. Only one branch of a `parallel` block may
+//! move the drivetrain. Preset and pneumatic names are checked against the robot's
+//! [`ScriptNames`] when a script route is selected, and a script run with a
+//! [`Mirror`](super::Mirror) mirrors its points, headings and side-specific pneumatics.
+//!
+//! Robots implement [`ScriptRobot`] to run [`SCRIPT_PATH`] from their route list.
+
+mod parse;
+mod robot;
+mod run;
+
+use alloc::{string::String, vec::Vec};
+use core::{fmt, time::Duration};
+
+use evian::math::{Angle, Vec2};
+use vexide::{fs, io};
+
+pub use self::{
+    parse::{ParseError, ParseErrorKind},
+    robot::{check_script, load_script, run_script, script_start, ScriptRobot, SCRIPT_PATH},
+    run::{Mechanisms, ScriptContext, ScriptError, ScriptErrorKind, ScriptNames},
+};
+use super::StartPose;
+use crate::subsystems::intake::RingColor;
+
+/// A parsed route script.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Script {
+    pub steps: Vec<Step>,
+}
+
+impl Script {
+    /// Parses a script from its text format.
+    pub fn parse(source: &str) -> Result<Self, ParseError> {
+        parse::parse(source).map(|steps| Self { steps })
+    }
+
+    /// Reads and parses a script from a file on the SD card.
+    pub fn load(path: &str) -> Result<Self, LoadError> {
+        let source = fs::read_to_string(path).map_err(LoadError::Io)?;
+
+        Self::parse(&source).map_err(LoadError::Parse)
+    }
+
+    /// Where the script places the robot, if it starts with a `start` step.
+    pub fn start(&self) -> Option<StartPose> {
+        match self.steps.first()?.kind {
+            StepKind::Start(pose) => Some(pose),
+            _ => None,
+        }
+    }
+}
+
+/// A single step of a [`Script`], along with the line it was parsed from.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Step {
+    pub line: usize,
+    pub kind: StepKind,
+}
+
+impl Step {
+    /// Returns `true` if this step (or any step nested in it) moves the drivetrain.
+    pub fn uses_drivetrain(&self) -> bool {
+        match &self.kind {
+            StepKind::MoveTo { .. } | StepKind::Turn { .. } | StepKind::Drive { .. } => true,
+            StepKind::Parallel(steps) | StepKind::Sequence(steps) => {
+                steps.iter().any(Step::uses_drivetrain)
+            }
+            _ => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum StepKind {
+    Start(StartPose),
+    MoveTo {
+        point: Vec2<f64>,
+        reverse: bool,
+        options: MotionOptions,
+    },
+    Turn {
+        heading: Angle,
+        options: MotionOptions,
+    },
+    Drive {
+        distance: f64,
+        heading: Option<Angle>,
+        options: MotionOptions,
+    },
+    Intake(IntakeMode),
+    LadyBrown(String),
+    Pneumatic {
+        name: String,
+        high: bool,
+    },
+    Wait(Duration),
+    Await {
+        condition: Condition,
+        timeout: Option<Duration>,
+    },
+    Parallel(Vec<Step>),
+    Sequence(Vec<Step>),
+}
+
+impl StepKind {
+    /// The keyword the step is written with, which also names it in the route's
+    /// [`Timeline`](super::Timeline).
+    pub const fn keyword(&self) -> &'static str {
+        match self {
+            Self::Start(_) => "start",
+            Self::MoveTo { .. } => "move_to",
+            Self::Turn { .. } => "turn",
+            Self::Drive { .. } => "drive",
+            Self::Intake(_) => "intake",
+            Self::LadyBrown(_) => "lady_brown",
+            Self::Pneumatic { .. } => "set",
+            Self::Wait(_) => "wait",
+            Self::Await { .. } => "await",
+            Self::Parallel(_) => "parallel",
+            Self::Sequence(_) => "sequence",
+        }
+    }
+}
+
+/// Overrides applied to a motion's defaults.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct MotionOptions {
+    pub timeout: Option<Duration>,
+
+    /// Maximum linear output, in volts.
+    pub output_limit: Option<f64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IntakeMode {
+    Voltage(f64),
+    TopVoltage(f64),
+    BottomVoltage(f64),
+    Raise,
+    Lower,
+    JamPrevention(bool),
+    RejectColor(Option<RingColor>),
+}
+
+/// Something an `await` step waits for.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Condition {
+    /// The robot is within `radius` inches of `point`.
+    Near { point: Vec2<f64>, radius: f64 },
+}
+
+/// Error returned by [`Script::load`].
+#[derive(Debug)]
+pub enum LoadError {
+    /// The file couldn't be read.
+    Io(io::Error),
+
+    /// The file isn't a valid script.
+    Parse(ParseError),
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "couldn't read script: {err}"),
+            Self::Parse(err) => write!(f, "{err}"),
+        }
+    }
+}
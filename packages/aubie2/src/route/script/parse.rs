@@ -0,0 +1,600 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{fmt, str::SplitWhitespace, time::Duration};
+
+use evian::math::{Angle, Vec2};
+
+use super::{Condition, IntakeMode, MotionOptions, Step, StepKind};
+use crate::{route::StartPose, subsystems::intake::RingColor};
+
+/// Error returned when a script can't be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line the error occurred on.
+    pub line: usize,
+    pub kind: ParseErrorKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// The line doesn't start with a known step.
+    UnknownStep(String),
+
+    /// A required argument is missing.
+    MissingArgument(&'static str),
+
+    /// An argument that should be a number isn't one.
+    InvalidNumber(String),
+
+    /// An argument that isn't accepted by the step.
+    UnexpectedArgument(String),
+
+    /// An `end` without a matching `parallel` or `sequence`.
+    UnexpectedEnd,
+
+    /// A `parallel` or `sequence` block that's never closed.
+    UnclosedBlock,
+
+    /// A `parallel` block with more than one branch that moves the drivetrain.
+    ConflictingMotions,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: ", self.line)?;
+
+        match &self.kind {
+            ParseErrorKind::UnknownStep(step) => write!(f, "unknown step `{step}`"),
+            ParseErrorKind::MissingArgument(arg) => write!(f, "missing {arg}"),
+            ParseErrorKind::InvalidNumber(arg) => write!(f, "`{arg}` isn't a valid number"),
+            ParseErrorKind::UnexpectedArgument(arg) => write!(f, "unexpected argument `{arg}`"),
+            ParseErrorKind::UnexpectedEnd => write!(f, "`end` without an open block"),
+            ParseErrorKind::UnclosedBlock => write!(f, "block is never closed with `end`"),
+            ParseErrorKind::ConflictingMotions => {
+                write!(f, "more than one branch of `parallel` moves the drivetrain")
+            }
+        }
+    }
+}
+
+/// A `parallel` or `sequence` block that hasn't been closed yet.
+struct Block {
+    line: usize,
+    parallel: bool,
+    steps: Vec<Step>,
+}
+
+pub(super) fn parse(source: &str) -> Result<Vec<Step>, ParseError> {
+    let mut steps = Vec::new();
+    let mut blocks: Vec<Block> = Vec::new();
+
+    for (index, text) in source.lines().enumerate() {
+        let line = index + 1;
+        let text = text.split_once('#').map_or(text, |(text, _)| text);
+        let mut args = Args {
+            line,
+            tokens: text.split_whitespace(),
+        };
+
+        let Some(name) = args.next() else {
+            continue;
+        };
+
+        let step = match name {
+            "parallel" | "sequence" => {
+                args.finish()?;
+                blocks.push(Block {
+                    line,
+                    parallel: name == "parallel",
+                    steps: Vec::new(),
+                });
+                continue;
+            }
+            "end" => {
+                args.finish()?;
+                let block = blocks.pop().ok_or(ParseError {
+                    line,
+                    kind: ParseErrorKind::UnexpectedEnd,
+                })?;
+
+                close(block)?
+            }
+            name => {
+                let kind = parse_step(name, &mut args)?;
+                args.finish()?;
+
+                Step { line, kind }
+            }
+        };
+
+        match blocks.last_mut() {
+            Some(block) => block.steps.push(step),
+            None => steps.push(step),
+        }
+    }
+
+    if let Some(block) = blocks.pop() {
+        return Err(ParseError {
+            line: block.line,
+            kind: ParseErrorKind::UnclosedBlock,
+        });
+    }
+
+    Ok(steps)
+}
+
+fn close(block: Block) -> Result<Step, ParseError> {
+    if !block.parallel {
+        return Ok(Step {
+            line: block.line,
+            kind: StepKind::Sequence(block.steps),
+        });
+    }
+
+    if block
+        .steps
+        .iter()
+        .filter(|step| step.uses_drivetrain())
+        .count()
+        > 1
+    {
+        return Err(ParseError {
+            line: block.line,
+            kind: ParseErrorKind::ConflictingMotions,
+        });
+    }
+
+    Ok(Step {
+        line: block.line,
+        kind: StepKind::Parallel(block.steps),
+    })
+}
+
+fn parse_step(name: &str, args: &mut Args<'_>) -> Result<StepKind, ParseError> {
+    Ok(match name {
+        "start" => StepKind::Start(StartPose::from_degrees(
+            args.number("x")?,
+            args.number("y")?,
+            args.number("heading")?,
+        )),
+        "move_to" => {
+            let point = Vec2::new(args.number("x")?, args.number("y")?);
+            let mut reverse = false;
+            let mut options = MotionOptions::default();
+
+            while let Some(arg) = args.next() {
+                match arg {
+                    "reverse" => reverse = true,
+                    "timeout" => options.timeout = Some(args.millis("timeout")?),
+                    "limit" => options.output_limit = Some(args.number("output limit")?),
+                    arg => return Err(args.unexpected(arg)),
+                }
+            }
+
+            StepKind::MoveTo {
+                point,
+                reverse,
+                options,
+            }
+        }
+        "turn" => {
+            let heading = args.heading()?;
+            let mut options = MotionOptions::default();
+
+            while let Some(arg) = args.next() {
+                match arg {
+                    "timeout" => options.timeout = Some(args.millis("timeout")?),
+                    arg => return Err(args.unexpected(arg)),
+                }
+            }
+
+            StepKind::Turn { heading, options }
+        }
+        "drive" => {
+            let distance = args.number("distance")?;
+            let mut heading = None;
+            let mut options = MotionOptions::default();
+
+            while let Some(arg) = args.next() {
+                match arg {
+                    "at" => heading = Some(args.heading()?),
+                    "timeout" => options.timeout = Some(args.millis("timeout")?),
+                    "limit" => options.output_limit = Some(args.number("output limit")?),
+                    arg => return Err(args.unexpected(arg)),
+                }
+            }
+
+            StepKind::Drive {
+                distance,
+                heading,
+                options,
+            }
+        }
+        "intake" => StepKind::Intake(match args.expect("intake mode")? {
+            "top" => IntakeMode::TopVoltage(args.number("voltage")?),
+            "bottom" => IntakeMode::BottomVoltage(args.number("voltage")?),
+            "stop" => IntakeMode::Voltage(0.0),
+            "raise" => IntakeMode::Raise,
+            "lower" => IntakeMode::Lower,
+            "jam" => IntakeMode::JamPrevention(match args.expect("`on` or `off`")? {
+                "on" => true,
+                "off" => false,
+                arg => return Err(args.unexpected(arg)),
+            }),
+            "reject" => IntakeMode::RejectColor(match args.expect("`red`, `blue` or `off`")? {
+                "red" => Some(RingColor::Red),
+                "blue" => Some(RingColor::Blue),
+                "off" => None,
+                arg => return Err(args.unexpected(arg)),
+            }),
+            voltage => IntakeMode::Voltage(args.parse_number(voltage)?),
+        }),
+        "lady_brown" => StepKind::LadyBrown(args.expect("preset")?.to_string()),
+        "set" => {
+            let name = args.expect("pneumatic")?.to_string();
+            let high = match args.expect("`high` or `low`")? {
+                "high" => true,
+                "low" => false,
+                arg => return Err(args.unexpected(arg)),
+            };
+
+            StepKind::Pneumatic { name, high }
+        }
+        "wait" => StepKind::Wait(args.millis("duration")?),
+        "await" => {
+            let condition = match args.expect("condition")? {
+                "near" => {
+                    let point = Vec2::new(args.number("x")?, args.number("y")?);
+                    args.keyword("within")?;
+
+                    Condition::Near {
+                        point,
+                        radius: args.number("radius")?,
+                    }
+                }
+                arg => return Err(args.unexpected(arg)),
+            };
+            let timeout = match args.next() {
+                Some("timeout") => Some(args.millis("timeout")?),
+                Some(arg) => return Err(args.unexpected(arg)),
+                None => None,
+            };
+
+            StepKind::Await { condition, timeout }
+        }
+        name => {
+            return Err(ParseError {
+                line: args.line,
+                kind: ParseErrorKind::UnknownStep(name.to_string()),
+            })
+        }
+    })
+}
+
+/// The arguments on a line after its step name.
+struct Args<'a> {
+    line: usize,
+    tokens: SplitWhitespace<'a>,
+}
+
+impl<'a> Args<'a> {
+    fn next(&mut self) -> Option<&'a str> {
+        self.tokens.next()
+    }
+
+    fn error(&self, kind: ParseErrorKind) -> ParseError {
+        ParseError {
+            line: self.line,
+            kind,
+        }
+    }
+
+    fn unexpected(&self, arg: &str) -> ParseError {
+        self.error(ParseErrorKind::UnexpectedArgument(arg.to_string()))
+    }
+
+    fn expect(&mut self, name: &'static str) -> Result<&'a str, ParseError> {
+        self.next()
+            .ok_or_else(|| self.error(ParseErrorKind::MissingArgument(name)))
+    }
+
+    fn keyword(&mut self, keyword: &'static str) -> Result<(), ParseError> {
+        match self.expect(keyword)? {
+            arg if arg == keyword => Ok(()),
+            arg => Err(self.unexpected(arg)),
+        }
+    }
+
+    fn parse_number(&self, arg: &str) -> Result<f64, ParseError> {
+        arg.parse::<f64>()
+            .ok()
+            .filter(|number| number.is_finite())
+            .ok_or_else(|| self.error(ParseErrorKind::InvalidNumber(arg.to_string())))
+    }
+
+    fn number(&mut self, name: &'static str) -> Result<f64, ParseError> {
+        let arg = self.expect(name)?;
+        self.parse_number(arg)
+    }
+
+    /// Parses a heading in degrees.
+    fn heading(&mut self) -> Result<Angle, ParseError> {
+        self.number("heading").map(Angle::from_degrees)
+    }
+
+    /// Parses a duration in whole milliseconds.
+    fn millis(&mut self, name: &'static str) -> Result<Duration, ParseError> {
+        let arg = self.expect(name)?;
+
+        arg.parse::<u64>()
+            .map(Duration::from_millis)
+            .map_err(|_| self.error(ParseErrorKind::InvalidNumber(arg.to_string())))
+    }
+
+    /// Checks that there are no arguments left.
+    fn finish(mut self) -> Result<(), ParseError> {
+        match self.next() {
+            Some(arg) => Err(self.unexpected(arg)),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+    use crate::route::script::Script;
+
+    fn kinds(source: &str) -> Vec<StepKind> {
+        parse(source)
+            .unwrap()
+            .into_iter()
+            .map(|step| step.kind)
+            .collect()
+    }
+
+    fn error(source: &str) -> ParseError {
+        parse(source).unwrap_err()
+    }
+
+    #[test]
+    fn parses_steps() {
+        let source = "
+            start 10 -63 214
+            move_to 26 -53 reverse timeout 1500 limit 6
+            turn 0
+            drive -26 at 90 limit 4
+            intake top 12
+            intake stop
+            intake reject blue
+            lady_brown raised
+            set clamp high
+            wait 350
+            await near 56 -21 within 3 timeout 2000
+        ";
+
+        assert_eq!(
+            kinds(source),
+            vec![
+                StepKind::Start(StartPose::from_degrees(10.0, -63.0, 214.0)),
+                StepKind::MoveTo {
+                    point: Vec2::new(26.0, -53.0),
+                    reverse: true,
+                    options: MotionOptions {
+                        timeout: Some(Duration::from_millis(1500)),
+                        output_limit: Some(6.0),
+                    },
+                },
+                StepKind::Turn {
+                    heading: Angle::from_degrees(0.0),
+                    options: MotionOptions::default(),
+                },
+                StepKind::Drive {
+                    distance: -26.0,
+                    heading: Some(Angle::from_degrees(90.0)),
+                    options: MotionOptions {
+                        timeout: None,
+                        output_limit: Some(4.0),
+                    },
+                },
+                StepKind::Intake(IntakeMode::TopVoltage(12.0)),
+                StepKind::Intake(IntakeMode::Voltage(0.0)),
+                StepKind::Intake(IntakeMode::RejectColor(Some(RingColor::Blue))),
+                StepKind::LadyBrown("raised".to_string()),
+                StepKind::Pneumatic {
+                    name: "clamp".to_string(),
+                    high: true,
+                },
+                StepKind::Wait(Duration::from_millis(350)),
+                StepKind::Await {
+                    condition: Condition::Near {
+                        point: Vec2::new(56.0, -21.0),
+                        radius: 3.0,
+                    },
+                    timeout: Some(Duration::from_millis(2000)),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn skips_comments_and_blank_lines() {
+        let steps = parse("# Comment\n\nwait 100 # trailing comment\n   \nintake 12").unwrap();
+
+        assert_eq!(
+            steps,
+            vec![
+                Step {
+                    line: 3,
+                    kind: StepKind::Wait(Duration::from_millis(100)),
+                },
+                Step {
+                    line: 5,
+                    kind: StepKind::Intake(IntakeMode::Voltage(12.0)),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn nests_blocks() {
+        let source = "
+            parallel
+                move_to 41 -53
+                sequence
+                    wait 200
+                    intake 12
+                end
+            end
+        ";
+
+        assert_eq!(
+            parse(source).unwrap(),
+            vec![Step {
+                line: 2,
+                kind: StepKind::Parallel(vec![
+                    Step {
+                        line: 3,
+                        kind: StepKind::MoveTo {
+                            point: Vec2::new(41.0, -53.0),
+                            reverse: false,
+                            options: MotionOptions::default(),
+                        },
+                    },
+                    Step {
+                        line: 4,
+                        kind: StepKind::Sequence(vec![
+                            Step {
+                                line: 5,
+                                kind: StepKind::Wait(Duration::from_millis(200)),
+                            },
+                            Step {
+                                line: 6,
+                                kind: StepKind::Intake(IntakeMode::Voltage(12.0)),
+                            },
+                        ]),
+                    },
+                ]),
+            }]
+        );
+    }
+
+    #[test]
+    fn starts_from_first_step() {
+        let script = Script::parse("# Comment\nstart 10 -63 214\nwait 100").unwrap();
+        assert_eq!(
+            script.start(),
+            Some(StartPose::from_degrees(10.0, -63.0, 214.0))
+        );
+
+        let script = Script::parse("wait 100\nstart 10 -63 214").unwrap();
+        assert_eq!(script.start(), None);
+    }
+
+    #[test]
+    fn rejects_bad_steps() {
+        assert_eq!(
+            error("wait 100\nspin 3"),
+            ParseError {
+                line: 2,
+                kind: ParseErrorKind::UnknownStep("spin".to_string()),
+            }
+        );
+        assert_eq!(
+            error("move_to 10").kind,
+            ParseErrorKind::MissingArgument("y")
+        );
+        assert_eq!(
+            error("set clamp").kind,
+            ParseErrorKind::MissingArgument("`high` or `low`")
+        );
+        assert_eq!(
+            error("turn 90 reverse").kind,
+            ParseErrorKind::UnexpectedArgument("reverse".to_string())
+        );
+        assert_eq!(
+            error("wait 100 200").kind,
+            ParseErrorKind::UnexpectedArgument("200".to_string())
+        );
+        assert_eq!(
+            error("await near 0 0 inside 3").kind,
+            ParseErrorKind::UnexpectedArgument("inside".to_string())
+        );
+    }
+
+    #[test]
+    fn rejects_bad_numbers() {
+        assert_eq!(
+            error("drive ten").kind,
+            ParseErrorKind::InvalidNumber("ten".to_string())
+        );
+        assert_eq!(
+            error("turn inf").kind,
+            ParseErrorKind::InvalidNumber("inf".to_string())
+        );
+        assert_eq!(
+            error("intake NaN").kind,
+            ParseErrorKind::InvalidNumber("NaN".to_string())
+        );
+
+        // Durations are whole milliseconds.
+        assert_eq!(
+            error("wait 1.5").kind,
+            ParseErrorKind::InvalidNumber("1.5".to_string())
+        );
+        assert_eq!(
+            error("wait -100").kind,
+            ParseErrorKind::InvalidNumber("-100".to_string())
+        );
+    }
+
+    #[test]
+    fn rejects_unbalanced_blocks() {
+        assert_eq!(
+            error("wait 100\nend"),
+            ParseError {
+                line: 2,
+                kind: ParseErrorKind::UnexpectedEnd,
+            }
+        );
+        assert_eq!(
+            error("wait 100\nparallel\nsequence\nend"),
+            ParseError {
+                line: 2,
+                kind: ParseErrorKind::UnclosedBlock,
+            }
+        );
+        assert_eq!(
+            error("parallel now\nend").kind,
+            ParseErrorKind::UnexpectedArgument("now".to_string())
+        );
+    }
+
+    #[test]
+    fn rejects_parallel_motions() {
+        let source = "
+            parallel
+                turn 90
+                sequence
+                    wait 100
+                    drive 12
+                end
+            end
+        ";
+
+        assert_eq!(
+            error(source),
+            ParseError {
+                line: 2,
+                kind: ParseErrorKind::ConflictingMotions,
+            }
+        );
+
+        // Motions one after another in a sequence don't conflict.
+        assert!(parse("sequence\nturn 90\ndrive 12\nend").is_ok());
+    }
+}
@@ -0,0 +1,73 @@
+use alloc::string::{String, ToString};
+
+use log::error;
+
+use super::{Script, ScriptContext, ScriptNames};
+use crate::route::{Mirror, StartPose};
+
+/// Script run by script routes, on the SD card.
+pub const SCRIPT_PATH: &str = "route.txt";
+
+/// A robot that can run [`SCRIPT_PATH`] as a route.
+///
+/// Script routes are listed with [`script_start`], [`check_script`] and [`run_script`]:
+///
+/// ```ignore
+/// "Red Script" {
+///     alliance: Red,
+///     description: "Runs route.txt from the SD card",
+///     start_from: aubie2::route::script::script_start::<Robot>,
+///     check: aubie2::route::script::check_script::<Robot>,
+///     run: aubie2::route::script::run_script::<Robot>,
+///     mirrored: "Blue Script",
+/// },
+/// ```
+pub trait ScriptRobot {
+    /// The presets and pneumatics the robot's scripts can name.
+    const SCRIPT_NAMES: ScriptNames;
+
+    /// Borrows everything a script needs to run on the robot.
+    fn script_context(&mut self) -> ScriptContext<'_>;
+}
+
+/// Loads [`SCRIPT_PATH`] and checks it against `R`'s [`ScriptNames`].
+pub fn load_script<R: ScriptRobot>() -> Result<Script, String> {
+    let script = Script::load(SCRIPT_PATH).map_err(|err| err.to_string())?;
+    script
+        .check(&R::SCRIPT_NAMES)
+        .map_err(|err| err.to_string())?;
+
+    Ok(script)
+}
+
+/// Route check for script routes, so a missing or broken script is reported when the route is
+/// selected instead of when autonomous starts.
+pub fn check_script<R: ScriptRobot>() -> Result<(), String> {
+    load_script::<R>().map(|_| ())
+}
+
+/// Start pose for script routes, from the script's `start` step. Returns `None` if the script
+/// can't be loaded or doesn't pass [`check_script`].
+pub fn script_start<R: ScriptRobot>(mirror: Mirror) -> Option<StartPose> {
+    let start = load_script::<R>().ok()?.start()?;
+
+    Some(StartPose {
+        position: mirror.point(start.position),
+        heading: mirror.heading(start.heading),
+    })
+}
+
+/// Route body for script routes.
+pub async fn run_script<R: ScriptRobot>(robot: &mut R, mirror: Mirror) {
+    let script = match load_script::<R>() {
+        Ok(script) => script,
+        Err(err) => {
+            error!("Couldn't run `{}`: {}", SCRIPT_PATH, err);
+            return;
+        }
+    };
+
+    if let Err(err) = script.run(robot.script_context(), mirror).await {
+        error!("Script `{}` failed at {}", SCRIPT_PATH, err);
+    }
+}
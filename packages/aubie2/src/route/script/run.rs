@@ -0,0 +1,433 @@
+use alloc::{boxed::Box, string::String, vec::Vec};
+use core::{cell::RefCell, fmt, future::Future, pin::Pin, time::Duration};
+
+use evian::{
+    control::loops::{AngularPid, Pid},
+    motion::{Basic, Seeking},
+    prelude::{Differential, Drivetrain},
+    tracking::TracksPosition,
+};
+use futures::future::join_all;
+use log::{debug, warn};
+use vexide::{
+    prelude::AdiDigitalOut,
+    time::{sleep, Instant},
+};
+
+use super::{Condition, IntakeMode, Script, Step, StepKind};
+use crate::{
+    route::{Mirror, StartPose, Timeline},
+    subsystems::{lady_brown::LadyBrownTarget, Intake, LadyBrown},
+    tracking::SharedTracking,
+};
+
+/// How often `await` conditions are checked.
+const AWAIT_INTERVAL: Duration = Duration::from_millis(10);
+
+/// The names scripts can use for a robot's lady brown presets and pneumatics.
+///
+/// These don't borrow the robot, so a script can be [checked](Script::check) against them
+/// before it runs.
+#[derive(Debug, Clone, Copy)]
+pub struct ScriptNames {
+    /// Targets that `lady_brown <preset>` can move to.
+    pub lady_brown_presets: &'static [(&'static str, LadyBrownTarget)],
+
+    /// Pneumatics that `set <pneumatic>` can control.
+    pub pneumatics: &'static [&'static str],
+
+    /// Pairs of side-specific pneumatics that are swapped when a script is mirrored, such as
+    /// `("left_arm", "right_arm")`.
+    pub mirrored_pneumatics: &'static [(&'static str, &'static str)],
+}
+
+impl ScriptNames {
+    fn preset(&self, preset: &str) -> Option<LadyBrownTarget> {
+        self.lady_brown_presets
+            .iter()
+            .find(|(name, _)| *name == preset)
+            .map(|&(_, target)| target)
+    }
+
+    fn pneumatic(&self, pneumatic: &str) -> Option<usize> {
+        self.pneumatics.iter().position(|name| *name == pneumatic)
+    }
+}
+
+/// The robot's mechanisms that scripts can control, by name.
+pub struct Mechanisms<'a> {
+    pub intake: &'a mut Intake,
+    pub lady_brown: &'a mut LadyBrown,
+    pub names: &'a ScriptNames,
+
+    /// The pneumatics listed in [`ScriptNames::pneumatics`], in the same order.
+    pub pneumatics: Vec<&'a mut AdiDigitalOut>,
+}
+
+/// Everything a script needs to run on a robot.
+pub struct ScriptContext<'a> {
+    pub drivetrain: &'a mut Drivetrain<Differential, SharedTracking>,
+
+    /// Records the script's steps under their keywords, and stops motions that run past their
+    /// timeout. Steps in the branches of a `parallel` block that don't drive aren't recorded.
+    pub timeline: &'a mut Timeline,
+
+    /// Motion used for `turn` and `drive` steps. Its timeout is ignored in favor of `timeout`.
+    pub basic: Basic<Pid, AngularPid>,
+
+    /// Motion used for `move_to` steps. Its timeout is ignored in favor of `timeout`.
+    pub seeking: Seeking<Pid, AngularPid>,
+
+    /// Timeout for motion steps that don't set their own.
+    pub timeout: Option<Duration>,
+
+    pub mechanisms: Mechanisms<'a>,
+}
+
+/// Error returned when a script fails partway through.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScriptError {
+    /// 1-based line of the step that failed.
+    pub line: usize,
+    pub kind: ScriptErrorKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScriptErrorKind {
+    /// `lady_brown` named a preset the robot doesn't have.
+    UnknownPreset(String),
+
+    /// `set` named a pneumatic the robot doesn't have.
+    UnknownPneumatic(String),
+
+    /// The script doesn't start with a `start` step, so where the robot should be placed isn't
+    /// known.
+    NoStart,
+
+    /// A motion step in a branch that doesn't have the drivetrain. Parsed scripts never do this,
+    /// since only one branch of a `parallel` block may move.
+    NoDrivetrain,
+}
+
+impl fmt::Display for ScriptError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: ", self.line)?;
+
+        match &self.kind {
+            ScriptErrorKind::UnknownPreset(preset) => {
+                write!(f, "unknown lady brown preset `{preset}`")
+            }
+            ScriptErrorKind::UnknownPneumatic(name) => write!(f, "unknown pneumatic `{name}`"),
+            ScriptErrorKind::NoStart => write!(f, "script must start with a `start` step"),
+            ScriptErrorKind::NoDrivetrain => write!(f, "motion outside of the driving branch"),
+        }
+    }
+}
+
+impl Script {
+    /// Checks that the script starts with a `start` step and that every preset and pneumatic it
+    /// names exists on the robot, so a mistake is caught before the route starts rather than
+    /// partway through it.
+    pub fn check(&self, names: &ScriptNames) -> Result<(), ScriptError> {
+        if self.start().is_none() {
+            return Err(ScriptError {
+                line: self.steps.first().map_or(1, |step| step.line),
+                kind: ScriptErrorKind::NoStart,
+            });
+        }
+
+        check_steps(&self.steps, names)
+    }
+
+    /// Runs the script's steps in order, stopping at the first one that fails.
+    pub async fn run(&self, context: ScriptContext<'_>, mirror: Mirror) -> Result<(), ScriptError> {
+        let shared = Shared {
+            tracking: context.drivetrain.tracking.clone(),
+            mechanisms: RefCell::new(context.mechanisms),
+            mirror,
+        };
+        let mut drive = Drive {
+            drivetrain: context.drivetrain,
+            timeline: context.timeline,
+            basic: context.basic,
+            seeking: context.seeking,
+            timeout: context.timeout,
+        };
+
+        run_steps(&self.steps, Some(&mut drive), &shared).await
+    }
+}
+
+/// The drivetrain, its motions and the timeline recording them, which only one branch of a
+/// `parallel` block can have.
+struct Drive<'a> {
+    drivetrain: &'a mut Drivetrain<Differential, SharedTracking>,
+    timeline: &'a mut Timeline,
+    basic: Basic<Pid, AngularPid>,
+    seeking: Seeking<Pid, AngularPid>,
+    timeout: Option<Duration>,
+}
+
+/// State every branch can use. Mechanisms are only borrowed between awaits.
+struct Shared<'a> {
+    tracking: SharedTracking,
+    mechanisms: RefCell<Mechanisms<'a>>,
+    mirror: Mirror,
+}
+
+type StepFuture<'a> = Pin<Box<dyn Future<Output = Result<(), ScriptError>> + 'a>>;
+
+fn check_steps(steps: &[Step], names: &ScriptNames) -> Result<(), ScriptError> {
+    for step in steps {
+        let kind = match &step.kind {
+            StepKind::LadyBrown(preset) if names.preset(preset).is_none() => {
+                ScriptErrorKind::UnknownPreset(preset.clone())
+            }
+            StepKind::Pneumatic { name, .. } if names.pneumatic(name).is_none() => {
+                ScriptErrorKind::UnknownPneumatic(name.clone())
+            }
+            StepKind::Parallel(steps) | StepKind::Sequence(steps) => {
+                check_steps(steps, names)?;
+                continue;
+            }
+            _ => continue,
+        };
+
+        return Err(ScriptError {
+            line: step.line,
+            kind,
+        });
+    }
+
+    Ok(())
+}
+
+async fn run_steps(
+    steps: &[Step],
+    mut drive: Option<&mut Drive<'_>>,
+    shared: &Shared<'_>,
+) -> Result<(), ScriptError> {
+    for step in steps {
+        run_step(step, drive.as_deref_mut(), shared).await?;
+    }
+
+    Ok(())
+}
+
+fn run_step<'a>(
+    step: &'a Step,
+    mut drive: Option<&'a mut Drive<'_>>,
+    shared: &'a Shared<'_>,
+) -> StepFuture<'a> {
+    Box::pin(async move {
+        let mirror = shared.mirror;
+        let name = step.kind.keyword();
+        let error = |kind| ScriptError {
+            line: step.line,
+            kind,
+        };
+
+        debug!("Script line {}: {:?}", step.line, step.kind);
+
+        match &step.kind {
+            StepKind::Start(pose) => action(&mut drive, name, || {
+                StartPose {
+                    position: mirror.point(pose.position),
+                    heading: mirror.heading(pose.heading),
+                }
+                .apply(&shared.tracking);
+            }),
+            StepKind::MoveTo {
+                point,
+                reverse,
+                options,
+            } => {
+                let drive = drive.ok_or(error(ScriptErrorKind::NoDrivetrain))?;
+                let mut motion = drive
+                    .seeking
+                    .move_to_point(drive.drivetrain, mirror.point(*point));
+                if *reverse {
+                    motion.reverse();
+                }
+                if let Some(limit) = options.output_limit {
+                    motion.with_linear_output_limit(limit);
+                }
+
+                let timeout = options.timeout.or(drive.timeout);
+                drive.timeline.motion(name, timeout, motion).await;
+            }
+            StepKind::Turn { heading, options } => {
+                let drive = drive.ok_or(error(ScriptErrorKind::NoDrivetrain))?;
+                let motion = drive
+                    .basic
+                    .turn_to_heading(drive.drivetrain, mirror.heading(*heading));
+
+                let timeout = options.timeout.or(drive.timeout);
+                drive.timeline.motion(name, timeout, motion).await;
+            }
+            StepKind::Drive {
+                distance,
+                heading,
+                options,
+            } => {
+                let drive = drive.ok_or(error(ScriptErrorKind::NoDrivetrain))?;
+                let timeout = options.timeout.or(drive.timeout);
+
+                match heading {
+                    Some(heading) => {
+                        let mut motion = drive.basic.drive_distance_at_heading(
+                            drive.drivetrain,
+                            *distance,
+                            mirror.heading(*heading),
+                        );
+                        if let Some(limit) = options.output_limit {
+                            motion.with_linear_output_limit(limit);
+                        }
+
+                        drive.timeline.motion(name, timeout, motion).await;
+                    }
+                    None => {
+                        let mut motion = drive.basic.drive_distance(drive.drivetrain, *distance);
+                        if let Some(limit) = options.output_limit {
+                            motion.with_linear_output_limit(limit);
+                        }
+
+                        drive.timeline.motion(name, timeout, motion).await;
+                    }
+                }
+            }
+            StepKind::Intake(mode) => {
+                let mut mechanisms = shared.mechanisms.borrow_mut();
+                let intake = &mut *mechanisms.intake;
+
+                action(&mut drive, name, || match *mode {
+                    IntakeMode::Voltage(voltage) => intake.set_voltage(voltage),
+                    IntakeMode::TopVoltage(voltage) => intake.set_top_voltage(voltage),
+                    IntakeMode::BottomVoltage(voltage) => intake.set_bottom_voltage(voltage),
+                    IntakeMode::Raise => {
+                        _ = intake.raise();
+                    }
+                    IntakeMode::Lower => {
+                        _ = intake.lower();
+                    }
+                    IntakeMode::JamPrevention(true) => intake.enable_jam_prevention(),
+                    IntakeMode::JamPrevention(false) => intake.disable_jam_prevention(),
+                    IntakeMode::RejectColor(color) => intake.set_reject_color(color),
+                });
+            }
+            StepKind::LadyBrown(preset) => {
+                let mut mechanisms = shared.mechanisms.borrow_mut();
+                let target = mechanisms
+                    .names
+                    .preset(preset)
+                    .ok_or_else(|| error(ScriptErrorKind::UnknownPreset(preset.clone())))?;
+
+                action(&mut drive, name, || {
+                    mechanisms.lady_brown.set_target(target)
+                });
+            }
+            StepKind::Pneumatic {
+                name: pneumatic,
+                high,
+            } => {
+                let mut mechanisms = shared.mechanisms.borrow_mut();
+                let names = mechanisms.names;
+                let pneumatic = mirror_pneumatic(names.mirrored_pneumatics, pneumatic, mirror);
+                let pneumatic = names
+                    .pneumatic(pneumatic)
+                    .and_then(|index| mechanisms.pneumatics.get_mut(index))
+                    .ok_or_else(|| error(ScriptErrorKind::UnknownPneumatic(pneumatic.into())))?;
+
+                action(&mut drive, name, || {
+                    _ = if *high {
+                        pneumatic.set_high()
+                    } else {
+                        pneumatic.set_low()
+                    };
+                });
+            }
+            StepKind::Wait(duration) => match drive {
+                Some(drive) => drive.timeline.wait(name, *duration).await,
+                None => sleep(*duration).await,
+            },
+            StepKind::Await { condition, timeout } => {
+                let is_met = || is_met(condition, &shared.tracking, mirror);
+
+                match drive {
+                    Some(drive) => {
+                        drive.timeline.wait_until(name, *timeout, is_met).await;
+                    }
+                    None => {
+                        let start = Instant::now();
+
+                        while !is_met() {
+                            if timeout.is_some_and(|timeout| start.elapsed() >= timeout) {
+                                warn!("Script line {}: timed out waiting.", step.line);
+                                break;
+                            }
+
+                            sleep(AWAIT_INTERVAL).await;
+                        }
+                    }
+                }
+            }
+            StepKind::Parallel(branches) => {
+                // A block that doesn't drive still gives the drivetrain to its first branch, so
+                // that branch's steps are recorded.
+                let driving = branches.iter().position(Step::uses_drivetrain).unwrap_or(0);
+                let branches = branches
+                    .iter()
+                    .enumerate()
+                    .map(|(index, branch)| {
+                        let drive = if index == driving { drive.take() } else { None };
+
+                        run_step(branch, drive, shared)
+                    })
+                    .collect::<Vec<_>>();
+
+                for result in join_all(branches).await {
+                    result?;
+                }
+            }
+            StepKind::Sequence(steps) => run_steps(steps, drive, shared).await?,
+        }
+
+        Ok(())
+    })
+}
+
+/// Runs a subsystem step through the timeline, if this branch has it.
+fn action<T>(drive: &mut Option<&mut Drive<'_>>, name: &'static str, f: impl FnOnce() -> T) -> T {
+    match drive {
+        Some(drive) => drive.timeline.action(name, f),
+        None => f(),
+    }
+}
+
+/// Swaps a side-specific pneumatic for its counterpart if the script is mirrored.
+fn mirror_pneumatic<'a>(pairs: &[(&'a str, &'a str)], name: &'a str, mirror: Mirror) -> &'a str {
+    pairs
+        .iter()
+        .find_map(|&(left, right)| {
+            if name == left {
+                Some(mirror.side(left, right))
+            } else if name == right {
+                Some(mirror.side(right, left))
+            } else {
+                None
+            }
+        })
+        .unwrap_or(name)
+}
+
+fn is_met(condition: &Condition, tracking: &SharedTracking, mirror: Mirror) -> bool {
+    match *condition {
+        Condition::Near { point, radius } => {
+            let point = mirror.point(point);
+            let position = tracking.position();
+            let (dx, dy) = (position.x - point.x, position.y - point.y);
+
+            dx * dx + dy * dy <= radius * radius
+        }
+    }
+}
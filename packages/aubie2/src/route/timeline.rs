@@ -0,0 +1,616 @@
+use alloc::{format, vec::Vec};
+use core::{fmt, future::Future, pin::pin, time::Duration};
+
+use evian::{
+    math::{Angle, Vec2},
+    prelude::Differential,
+};
+use futures::future::{select, Either};
+use log::{info, warn};
+use vexide::time::{sleep, Instant};
+
+use super::MATCH_PERIOD;
+use crate::tracking::{wrap, Pose, SharedTracking};
+
+/// Start of the log message written when [`Timeline::action`] runs an action.
+pub const ACTION_PREFIX: &str = "Action: ";
+
+/// How often [`Timeline::wait_until`] checks its condition.
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// How a [`Timeline`] step ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    /// A motion finished before its timeout.
+    Settled,
+
+    /// A motion or wait was still running when its timeout ran out, and was stopped.
+    TimedOut,
+
+    /// A subsystem action or wait finished.
+    Done,
+
+    /// A step was skipped because there wasn't enough time left in the period.
+    OutOfTime,
+}
+
+impl Outcome {
+    /// Returns `true` if the step finished as planned.
+    pub const fn is_ok(self) -> bool {
+        matches!(self, Self::Settled | Self::Done)
+    }
+
+    pub const fn timed_out(self) -> bool {
+        matches!(self, Self::TimedOut)
+    }
+
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Settled => "settled",
+            Self::TimedOut => "timeout",
+            Self::Done => "done",
+            Self::OutOfTime => "no time",
+        }
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+/// Error returned by [`Timeline::critical`] when a step the rest of the route depends on fails,
+/// or by [`Timeline::ensure_time`] when there isn't enough time left for a step.
+///
+/// Routes put their critical steps in a section returning `Result<(), StepFailed>` and use `?`
+/// on them, so a failure skips the rest of the section and the route can run a fallback (back
+/// off and retry, skip to a safe ending, or park).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StepFailed {
+    pub name: &'static str,
+    pub outcome: Outcome,
+}
+
+impl fmt::Display for StepFailed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`{}` failed ({})", self.name, self.outcome)
+    }
+}
+
+/// A step recorded by a [`Timeline`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TimelineStep {
+    pub name: &'static str,
+
+    /// When the step started, relative to [`Timeline::start`].
+    pub start: Duration,
+    pub duration: Duration,
+    pub outcome: Outcome,
+    pub before: Pose,
+    pub after: Pose,
+}
+
+/// The result of comparing the tracked pose against a route's expected pose.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Checkpoint {
+    pub name: &'static str,
+
+    /// When the checkpoint was reached, relative to [`Timeline::start`].
+    pub time: Duration,
+    pub expected_position: Vec2<f64>,
+    pub expected_heading: Option<Angle>,
+    pub actual: Pose,
+
+    /// Distance between the expected and tracked positions, in inches.
+    pub position_error: f64,
+
+    /// Signed difference between the tracked and expected headings.
+    pub heading_error: Option<Angle>,
+    pub within_tolerance: bool,
+}
+
+/// Records how long each step of a route takes, and where the robot was before and after it.
+///
+/// Routes run their motions, waits and subsystem actions through the timeline:
+///
+/// ```ignore
+/// let tl = &mut self.timeline;
+/// tl.action("Raise lady brown", || self.lady_brown.set_target(Self::LADY_BROWN_RAISED));
+/// tl.wait("Score", Duration::from_millis(950)).await;
+/// tl.motion(
+///     "Back to goal",
+///     Some(Duration::from_secs(5)),
+///     seeking.move_to_point(dt, (26.0, -53.0)).reverse(),
+/// )
+/// .await;
+/// ```
+///
+/// Routes can also declare checkpoints, where the tracked pose is compared against where the
+/// robot should be so that odometry drift can be told apart from a motion that missed:
+///
+/// ```ignore
+/// tl.checkpoint("Goal", mirror.point((26.0, -53.0)), Some(mirror.heading(0.0.deg())));
+/// ```
+///
+/// The timeline also keeps the autonomous clock, so routes can skip to their ending when a step
+/// won't fit in the time that's left:
+///
+/// ```ignore
+/// tl.ensure_time("Corner", Duration::from_secs(9))?;
+/// ```
+///
+/// Routes that [plan](Timeline::plan) their checks up front are warned as soon as they fall
+/// behind, rather than only once they reach a check they can't pass.
+///
+/// Steps run before [`Timeline::start`] aren't recorded.
+pub struct Timeline {
+    tracking: Option<SharedTracking>,
+    motors: Option<Differential>,
+    start: Option<Instant>,
+    period: Duration,
+    overrun_reported: bool,
+    budgets: &'static [(&'static str, Duration)],
+    pace_reported: bool,
+    failure: Option<StepFailed>,
+    steps: Vec<TimelineStep>,
+    checkpoints: Vec<Checkpoint>,
+    position_tolerance: f64,
+    heading_tolerance: Angle,
+    drift_alerts: bool,
+}
+
+impl Default for Timeline {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Timeline {
+    /// Default distance from a checkpoint's expected position that counts as drift, in inches.
+    pub const POSITION_TOLERANCE: f64 = 3.0;
+
+    /// Default heading error at a checkpoint that counts as drift.
+    pub const HEADING_TOLERANCE: Angle = Angle::from_degrees(5.0);
+
+    pub const fn new() -> Self {
+        Self {
+            tracking: None,
+            motors: None,
+            start: None,
+            period: MATCH_PERIOD,
+            overrun_reported: false,
+            budgets: &[],
+            pace_reported: false,
+            failure: None,
+            steps: Vec::new(),
+            checkpoints: Vec::new(),
+            position_tolerance: Self::POSITION_TOLERANCE,
+            heading_tolerance: Self::HEADING_TOLERANCE,
+            drift_alerts: false,
+        }
+    }
+
+    /// Sets the drivetrain motors to stop when a motion times out.
+    ///
+    /// A motion that runs out of time is dropped partway through, which leaves the motors at
+    /// whatever voltage it last set.
+    pub fn with_motors(mut self, motors: Differential) -> Self {
+        self.motors = Some(motors);
+        self
+    }
+
+    /// Sets how far from a checkpoint the robot can be before it's reported as drift.
+    pub fn with_checkpoint_tolerance(mut self, position: f64, heading: Angle) -> Self {
+        self.position_tolerance = position;
+        self.heading_tolerance = heading;
+        self
+    }
+
+    /// Reports missed checkpoints as warnings, which also shows them on the controller (see
+    /// [`alerts`](crate::logger::alerts)), rather than as info.
+    pub fn with_drift_alerts(mut self, enabled: bool) -> Self {
+        self.drift_alerts = enabled;
+        self
+    }
+
+    /// Clears any recorded steps and checkpoints and starts recording a new run that has
+    /// `period` to finish.
+    pub fn start(&mut self, tracking: &SharedTracking, period: Duration) {
+        self.tracking = Some(tracking.clone());
+        self.start = Some(Instant::now());
+        self.period = period;
+        self.overrun_reported = false;
+        self.budgets = &[];
+        self.pace_reported = false;
+        self.failure = None;
+        self.steps.clear();
+        self.checkpoints.clear();
+    }
+
+    /// Time since [`Timeline::start`].
+    pub fn elapsed(&self) -> Duration {
+        self.start.map_or(Duration::ZERO, |start| start.elapsed())
+    }
+
+    /// Time left in the period.
+    pub fn remaining(&self) -> Duration {
+        self.period.saturating_sub(self.elapsed())
+    }
+
+    /// Declares the [`Timeline::ensure_time`] checks the route will make, in order, and the time
+    /// each needs. After every step and checkpoint, the time left is compared against the next
+    /// check still to come, and a warning is logged the first time it can't be passed.
+    ///
+    /// Call this after [`Timeline::start`], which clears the plan.
+    pub fn plan(&mut self, budgets: &'static [(&'static str, Duration)]) {
+        self.budgets = budgets;
+    }
+
+    /// Returns an error if less than `needed` is left in the period, so the route can skip to
+    /// its ending instead of starting a step it won't finish.
+    pub fn ensure_time(&mut self, name: &'static str, needed: Duration) -> Result<(), StepFailed> {
+        if self.has_time(name, needed) {
+            return Ok(());
+        }
+
+        // The route skips to its ending, so the checks it had planned after this one won't run.
+        self.budgets = &[];
+
+        warn!(
+            "Skipping `{}`: needs {:.1}s, {:.1}s left.",
+            name,
+            needed.as_secs_f64(),
+            self.remaining().as_secs_f64()
+        );
+
+        let before = self.pose();
+        self.record(name, Instant::now(), before, Outcome::OutOfTime);
+
+        Err(self.fail(StepFailed {
+            name,
+            outcome: Outcome::OutOfTime,
+        }))
+    }
+
+    /// Like [`Timeline::ensure_time`], but only warns if less than `needed` is left, for checks
+    /// whose time hasn't been measured yet and shouldn't cut the route short.
+    pub fn expect_time(&mut self, name: &'static str, needed: Duration) {
+        if !self.has_time(name, needed) {
+            warn!(
+                "Short on time at `{}`: needs {:.1}s, {:.1}s left.",
+                name,
+                needed.as_secs_f64(),
+                self.remaining().as_secs_f64()
+            );
+        }
+    }
+
+    /// Moves the [plan](Timeline::plan) past the check `name`, returning whether `needed` is
+    /// left in the period.
+    fn has_time(&mut self, name: &'static str, needed: Duration) -> bool {
+        if let Some(index) = self.budgets.iter().position(|&(budget, _)| budget == name) {
+            self.budgets = &self.budgets[index + 1..];
+        }
+
+        self.start.is_none() || self.remaining() >= needed
+    }
+
+    /// The first [`Timeline::critical`] step or [`Timeline::ensure_time`] check that failed since
+    /// [`Timeline::start`], or `None` if the route ran as planned rather than falling back.
+    pub const fn failure(&self) -> Option<StepFailed> {
+        self.failure
+    }
+
+    pub fn steps(&self) -> &[TimelineStep] {
+        &self.steps
+    }
+
+    pub fn checkpoints(&self) -> &[Checkpoint] {
+        &self.checkpoints
+    }
+
+    /// Compares the tracked pose against where the route expects the robot to be, logging the
+    /// deviation.
+    ///
+    /// Returns `None` if the timeline hasn't been started.
+    pub fn checkpoint(
+        &mut self,
+        name: &'static str,
+        position: impl Into<Vec2<f64>>,
+        heading: Option<Angle>,
+    ) -> Option<Checkpoint> {
+        let (Some(start), Some(actual)) = (self.start, self.pose()) else {
+            return None;
+        };
+
+        let position = position.into();
+        let position_error = position.distance(actual.position);
+        let heading_error = heading.map(|heading| {
+            Angle::from_radians(wrap(actual.heading.as_radians() - heading.as_radians()))
+        });
+        let within_tolerance = position_error <= self.position_tolerance
+            && heading_error.map_or(true, |error| {
+                error.as_radians().abs() <= self.heading_tolerance.as_radians()
+            });
+
+        let checkpoint = Checkpoint {
+            name,
+            time: start.elapsed(),
+            expected_position: position,
+            expected_heading: heading,
+            actual,
+            position_error,
+            heading_error,
+            within_tolerance,
+        };
+        self.checkpoints.push(checkpoint);
+        self.check_pace(name);
+
+        let heading_error = heading_error.map_or(0.0, |error| error.as_degrees());
+        if within_tolerance {
+            info!(
+                "Checkpoint `{}`: off by {:.1} in, {:.1}°.",
+                name, position_error, heading_error
+            );
+        } else if self.drift_alerts {
+            warn!(
+                "`{}` off by {:.1}in {:.0}°",
+                name, position_error, heading_error
+            );
+        } else {
+            info!(
+                "Checkpoint `{}` missed: off by {:.1} in, {:.1}° (at {}).",
+                name, position_error, heading_error, actual
+            );
+        }
+
+        Some(checkpoint)
+    }
+
+    /// Runs a motion, stopping it if it's still running after `timeout`.
+    ///
+    /// evian's motions don't say why they finished, so the timeline owns the timeout: motions
+    /// should be built without one, and are raced against `timeout` here instead.
+    pub async fn motion<F: Future>(
+        &mut self,
+        name: &'static str,
+        timeout: Option<Duration>,
+        motion: F,
+    ) -> Outcome {
+        let started = Instant::now();
+        let before = self.pose();
+
+        let settled = match timeout {
+            Some(timeout) => matches!(
+                select(pin!(motion), pin!(sleep(timeout))).await,
+                Either::Left(_)
+            ),
+            None => {
+                motion.await;
+                true
+            }
+        };
+
+        let outcome = if settled {
+            Outcome::Settled
+        } else {
+            warn!("`{}` timed out after {:?}.", name, started.elapsed());
+            if let Some(motors) = &mut self.motors {
+                _ = motors.set_voltages((0.0, 0.0));
+            }
+            Outcome::TimedOut
+        };
+        self.record(name, started, before, outcome);
+
+        outcome
+    }
+
+    /// Like [`Timeline::motion`], but returns an error if the motion didn't settle.
+    pub async fn critical<F: Future>(
+        &mut self,
+        name: &'static str,
+        timeout: Option<Duration>,
+        motion: F,
+    ) -> Result<(), StepFailed> {
+        match self.motion(name, timeout, motion).await {
+            outcome if outcome.is_ok() => Ok(()),
+            outcome => Err(self.fail(StepFailed { name, outcome })),
+        }
+    }
+
+    /// Waits for `duration`.
+    pub async fn wait(&mut self, name: &'static str, duration: Duration) {
+        let started = Instant::now();
+        let before = self.pose();
+
+        sleep(duration).await;
+
+        self.record(name, started, before, Outcome::Done);
+    }
+
+    /// Waits until `condition` is met, or for at most `timeout`.
+    pub async fn wait_until(
+        &mut self,
+        name: &'static str,
+        timeout: Option<Duration>,
+        mut condition: impl FnMut() -> bool,
+    ) -> Outcome {
+        let started = Instant::now();
+        let before = self.pose();
+
+        let outcome = loop {
+            if condition() {
+                break Outcome::Done;
+            }
+            if timeout.is_some_and(|timeout| started.elapsed() >= timeout) {
+                warn!("`{}` timed out after {:?}.", name, started.elapsed());
+                break Outcome::TimedOut;
+            }
+
+            sleep(POLL_INTERVAL).await;
+        };
+        self.record(name, started, before, outcome);
+
+        outcome
+    }
+
+    /// Runs a subsystem action.
+    ///
+    /// Actions are also logged as they happen (as [`ACTION_PREFIX`] followed by the name), so
+    /// they can be marked on a plot of the run.
+    pub fn action<T>(&mut self, name: &'static str, action: impl FnOnce() -> T) -> T {
+        let started = Instant::now();
+        let before = self.pose();
+
+        info!("{ACTION_PREFIX}{name}");
+
+        let output = action();
+
+        self.record(name, started, before, Outcome::Done);
+
+        output
+    }
+
+    /// Logs the recorded steps as a table followed by a summary of checkpoint drift, which also
+    /// saves them to the SD card log.
+    pub fn log(&self) {
+        self.log_steps();
+        self.log_drift();
+    }
+
+    fn log_steps(&self) {
+        if self.steps.is_empty() {
+            return;
+        }
+
+        info!("Route timeline:");
+        info!(
+            "{:>3} {:>7} {:>7} {:<8} {:<24} {:<20} {}",
+            "#", "start", "time", "outcome", "step", "from", "to"
+        );
+
+        for (index, step) in self.steps.iter().enumerate() {
+            info!(
+                "{:>3} {:>6.2}s {:>6.2}s {:<8} {:<24} {:<20} {}",
+                index + 1,
+                step.start.as_secs_f64(),
+                step.duration.as_secs_f64(),
+                step.outcome,
+                step.name,
+                format!("{}", step.before),
+                step.after
+            );
+        }
+
+        if let Some(slowest) = self.steps.iter().max_by_key(|step| step.duration) {
+            info!(
+                "Slowest step: `{}` ({:.2}s).",
+                slowest.name,
+                slowest.duration.as_secs_f64()
+            );
+        }
+    }
+
+    /// Logs a summary of how far the robot was from each checkpoint.
+    fn log_drift(&self) {
+        if self.checkpoints.is_empty() {
+            return;
+        }
+
+        let missed = self
+            .checkpoints
+            .iter()
+            .filter(|checkpoint| !checkpoint.within_tolerance)
+            .count();
+        let total_error = self
+            .checkpoints
+            .iter()
+            .map(|checkpoint| checkpoint.position_error)
+            .sum::<f64>();
+        let worst = self
+            .checkpoints
+            .iter()
+            .max_by(|a, b| a.position_error.total_cmp(&b.position_error));
+
+        info!(
+            "Drift: {}/{} checkpoints within tolerance, mean error {:.1} in.",
+            self.checkpoints.len() - missed,
+            self.checkpoints.len(),
+            total_error / self.checkpoints.len() as f64
+        );
+
+        if let Some(worst) = worst {
+            info!(
+                "Worst checkpoint: `{}` at {:.2}s, off by {:.1} in (expected ({:.1}, {:.1}), tracked {}).",
+                worst.name,
+                worst.time.as_secs_f64(),
+                worst.position_error,
+                worst.expected_position.x,
+                worst.expected_position.y,
+                worst.actual
+            );
+        }
+    }
+
+    /// Remembers the run's first failure, for [`Timeline::failure`].
+    fn fail(&mut self, failed: StepFailed) -> StepFailed {
+        self.failure.get_or_insert(failed);
+        failed
+    }
+
+    fn pose(&self) -> Option<Pose> {
+        self.tracking.as_ref().map(SharedTracking::pose)
+    }
+
+    fn record(
+        &mut self,
+        name: &'static str,
+        started: Instant,
+        before: Option<Pose>,
+        outcome: Outcome,
+    ) {
+        let (Some(start), Some(before), Some(after)) = (self.start, before, self.pose()) else {
+            return;
+        };
+
+        if !self.overrun_reported && start.elapsed() > self.period {
+            self.overrun_reported = true;
+            warn!(
+                "Route overran its {:.0}s period at `{}`.",
+                self.period.as_secs_f64(),
+                name
+            );
+        }
+
+        self.steps.push(TimelineStep {
+            name,
+            start: started.duration_since(start),
+            duration: started.elapsed(),
+            outcome,
+            before,
+            after,
+        });
+        self.check_pace(name);
+    }
+
+    /// Warns once if the time left can't cover the next [planned](Timeline::plan) check, which
+    /// means the route is on track to run out of time before it gets there.
+    fn check_pace(&mut self, name: &'static str) {
+        let Some(&(next, needed)) = self.budgets.first() else {
+            return;
+        };
+        if self.pace_reported || self.start.is_none() || self.remaining() >= needed {
+            return;
+        }
+
+        self.pace_reported = true;
+        warn!(
+            "Behind pace at `{}`: `{}` needs {:.1}s, {:.1}s left.",
+            name,
+            next,
+            needed.as_secs_f64(),
+            self.remaining().as_secs_f64()
+        );
+    }
+}
@@ -0,0 +1,183 @@
+use alloc::{boxed::Box, vec::Vec};
+use core::{future::Future, pin::pin, time::Duration};
+
+use evian::{
+    math::Vec2,
+    tracking::{TracksForwardTravel, TracksPosition},
+};
+use futures::future::{select, Either};
+use log::warn;
+use vexide::time::{sleep, Instant};
+
+use crate::tracking::SharedTracking;
+
+/// How often trigger conditions are checked while a motion runs.
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// A condition on the robot's progress through a motion.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Trigger {
+    /// The robot has travelled this many inches (forwards or backwards) since the motion
+    /// started.
+    Travelled(f64),
+
+    /// The robot is within `radius` inches of `point`, such as the motion's target.
+    Within { point: Vec2<f64>, radius: f64 },
+
+    /// The robot is inside the rectangle spanned by two corners.
+    Region { a: Vec2<f64>, b: Vec2<f64> },
+
+    /// This much time has passed since the motion started.
+    Elapsed(Duration),
+}
+
+/// Actions attached to a motion, each fired once when its [`Trigger`] is met.
+///
+/// ```ignore
+/// Triggers::new(&dt.tracking)
+///     .after_travel(10.0, || _ = self.intake.raise())
+///     .within((0.0, -62.0), 3.0, || _ = self.clamp.set_high())
+///     .run(seeking.move_to_point(dt, (0.0, -62.0)))
+///     .await;
+/// ```
+///
+/// Time triggers still fire if the motion finishes first, and the motion's output is returned
+/// once they have, like joining the motion with a sleep. Any other actions that haven't fired by
+/// the time the motion finishes are dropped with a warning.
+pub struct Triggers<'a> {
+    tracking: SharedTracking,
+    actions: Vec<(Trigger, Box<dyn FnOnce() + 'a>)>,
+}
+
+impl<'a> Triggers<'a> {
+    pub fn new(tracking: &SharedTracking) -> Self {
+        Self {
+            tracking: tracking.clone(),
+            actions: Vec::new(),
+        }
+    }
+
+    /// Fires `action` once `trigger` is met.
+    pub fn on(mut self, trigger: Trigger, action: impl FnOnce() + 'a) -> Self {
+        self.actions.push((trigger, Box::new(action)));
+        self
+    }
+
+    /// Fires `action` after travelling `distance` inches.
+    pub fn after_travel(self, distance: f64, action: impl FnOnce() + 'a) -> Self {
+        self.on(Trigger::Travelled(distance), action)
+    }
+
+    /// Fires `action` once within `radius` inches of `point`.
+    pub fn within(
+        self,
+        point: impl Into<Vec2<f64>>,
+        radius: f64,
+        action: impl FnOnce() + 'a,
+    ) -> Self {
+        self.on(
+            Trigger::Within {
+                point: point.into(),
+                radius,
+            },
+            action,
+        )
+    }
+
+    /// Fires `action` once inside the rectangle spanned by corners `a` and `b`.
+    pub fn in_region(
+        self,
+        a: impl Into<Vec2<f64>>,
+        b: impl Into<Vec2<f64>>,
+        action: impl FnOnce() + 'a,
+    ) -> Self {
+        self.on(
+            Trigger::Region {
+                a: a.into(),
+                b: b.into(),
+            },
+            action,
+        )
+    }
+
+    /// Fires `action` once `duration` has passed.
+    pub fn after(self, duration: Duration, action: impl FnOnce() + 'a) -> Self {
+        self.on(Trigger::Elapsed(duration), action)
+    }
+
+    /// Runs `motion` to completion, firing actions as their triggers are met.
+    pub async fn run<F: Future>(mut self, motion: F) -> F::Output {
+        let start = Instant::now();
+        let start_travel = self.tracking.forward_travel();
+        let mut motion = pin!(motion);
+
+        let output = loop {
+            self.fire(start, start_travel);
+
+            if let Either::Left((output, _)) =
+                select(motion.as_mut(), pin!(sleep(POLL_INTERVAL))).await
+            {
+                break output;
+            }
+        };
+
+        // The motion may have settled exactly on a trigger's boundary.
+        self.fire(start, start_travel);
+
+        // Time triggers are still met after the motion finishes, so wait for them.
+        while let Some(remaining) = self
+            .actions
+            .iter()
+            .filter_map(|(trigger, _)| match *trigger {
+                Trigger::Elapsed(duration) => Some(duration.saturating_sub(start.elapsed())),
+                _ => None,
+            })
+            .min()
+        {
+            sleep(remaining).await;
+            self.fire(start, start_travel);
+        }
+
+        if !self.actions.is_empty() {
+            warn!(
+                "{} trigger(s) never fired: {:?}",
+                self.actions.len(),
+                self.actions
+                    .iter()
+                    .map(|(trigger, _)| trigger)
+                    .collect::<Vec<_>>()
+            );
+        }
+
+        output
+    }
+
+    fn fire(&mut self, start: Instant, start_travel: f64) {
+        let elapsed = start.elapsed();
+        let travelled = (self.tracking.forward_travel() - start_travel).abs();
+        let position = self.tracking.position();
+
+        let mut index = 0;
+        while index < self.actions.len() {
+            let met = match self.actions[index].0 {
+                Trigger::Travelled(distance) => travelled >= distance,
+                Trigger::Within { point, radius } => {
+                    let (dx, dy) = (position.x - point.x, position.y - point.y);
+                    dx * dx + dy * dy <= radius * radius
+                }
+                Trigger::Region { a, b } => {
+                    (a.x.min(b.x)..=a.x.max(b.x)).contains(&position.x)
+                        && (a.y.min(b.y)..=a.y.max(b.y)).contains(&position.y)
+                }
+                Trigger::Elapsed(duration) => elapsed >= duration,
+            };
+
+            if met {
+                let (_, action) = self.actions.remove(index);
+                action();
+            } else {
+                index += 1;
+            }
+        }
+    }
+}
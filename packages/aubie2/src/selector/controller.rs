@@ -0,0 +1,161 @@
+//! Route picker on the controller's screen, for when the brain's screen can't be reached.
+//!
+//! Up and down on the d-pad browse the route list, and A selects the route being shown. The
+//! bottom line always shows the selected route, so the driver can check it right up until
+//! autonomous starts. If the robot is placed wrong for the selected route, the middle line says
+//! which way to turn it instead.
+
+use alloc::{format, string::String};
+use core::time::Duration;
+
+use evian::math::Angle;
+use vexide::{prelude::Controller, time::Instant};
+
+use crate::route::Route;
+
+/// Number of lines on the controller's screen.
+const LINES: usize = 3;
+
+/// Characters that fit on one line of the controller's screen.
+const LINE_WIDTH: usize = 19;
+
+/// Minimum time between writes to the controller.
+const WRITE_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Rumble played when the robot is found to be placed wrong.
+const RUMBLE_PATTERN: &str = "--";
+
+pub(super) struct ControllerPicker {
+    cursor: usize,
+    shown: [Option<String>; LINES],
+    last_write: Option<Instant>,
+    rumble_pending: bool,
+}
+
+impl ControllerPicker {
+    pub const fn new() -> Self {
+        Self {
+            cursor: 0,
+            shown: [None, None, None],
+            last_write: None,
+            rumble_pending: false,
+        }
+    }
+
+    /// Handles button presses, returning the index of the route that was selected with A.
+    pub fn poll<R>(&mut self, controller: &Controller, routes: &[&dyn Route<R>]) -> Option<usize> {
+        let state = controller.state().ok()?;
+
+        if routes.is_empty() {
+            return None;
+        }
+
+        if state.button_down.is_now_pressed() {
+            self.cursor = (self.cursor + 1) % routes.len();
+        }
+        if state.button_up.is_now_pressed() {
+            self.cursor = (self.cursor + routes.len() - 1) % routes.len();
+        }
+
+        state.button_a.is_now_pressed().then_some(self.cursor)
+    }
+
+    /// Forgets what's on the controller's screen, so every line is written again. Anything else
+    /// may have written to the screen since the picker last drew, such as driver alerts.
+    pub fn redraw(&mut self) {
+        self.shown = [None, None, None];
+    }
+
+    /// Moves the cursor to a route selected some other way, such as on the brain's screen.
+    pub fn follow(&mut self, index: usize) {
+        self.cursor = index;
+    }
+
+    /// Rumbles the controller the next time it's ready for a write.
+    pub fn rumble(&mut self) {
+        self.rumble_pending = true;
+    }
+
+    /// Writes the next line that's out of date to the controller's screen, if the controller is
+    /// ready for it.
+    ///
+    /// `misplacement` is how far the robot's heading is from the selected route's start heading,
+    /// if it's placed wrong.
+    pub fn draw<R>(
+        &mut self,
+        controller: &mut Controller,
+        routes: &[&dyn Route<R>],
+        selected: Option<usize>,
+        misplacement: Option<Angle>,
+    ) {
+        if self
+            .last_write
+            .is_some_and(|last_write| last_write.elapsed() < WRITE_INTERVAL)
+        {
+            return;
+        }
+
+        if self.rumble_pending {
+            self.rumble_pending = controller.try_rumble(RUMBLE_PATTERN).is_err();
+            self.last_write = Some(Instant::now());
+            return;
+        }
+
+        let lines = self.lines(routes, selected, misplacement);
+        let Some(line) = (0..LINES).find(|&line| self.shown[line].as_ref() != Some(&lines[line]))
+        else {
+            return;
+        };
+
+        // Padding overwrites anything left over from a longer previous line.
+        if controller
+            .screen
+            .try_set_text(
+                format!("{:<1$}", lines[line], LINE_WIDTH),
+                line as u8 + 1,
+                1,
+            )
+            .is_ok()
+        {
+            self.shown[line] = Some(lines[line].clone());
+        }
+        self.last_write = Some(Instant::now());
+    }
+
+    fn lines<R>(
+        &self,
+        routes: &[&dyn Route<R>],
+        selected: Option<usize>,
+        misplacement: Option<Angle>,
+    ) -> [String; LINES] {
+        let Some(route) = routes.get(self.cursor) else {
+            return [String::from("No routes"), String::new(), String::new()];
+        };
+
+        let status = if selected == Some(self.cursor) {
+            "selected"
+        } else {
+            "A to select"
+        };
+
+        // Positive errors mean the robot is turned counterclockwise, so it needs to turn right.
+        let middle = match misplacement {
+            Some(error) => format!(
+                "Turn {} {:.0} deg!",
+                if error.as_radians() > 0.0 { "R" } else { "L" },
+                error.as_degrees().abs()
+            ),
+            None => format!("{} - {}", route.alliance().name(), status),
+        };
+
+        [
+            format!("{}/{} {}", self.cursor + 1, routes.len(), route.name()),
+            middle,
+            format!(
+                "Run: {}",
+                selected.map_or("none", |index| routes[index].name())
+            ),
+        ]
+        .map(|line| line.chars().take(LINE_WIDTH).collect())
+    }
+}
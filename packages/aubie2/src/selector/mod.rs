@@ -0,0 +1,192 @@
+//! Autonomous Route Selector
+//!
+//! Lists a robot's routes while it's disabled before a match, and keeps track of which one was
+//! picked so it can be run when autonomous starts. Routes can be picked by tapping them on the
+//! brain's touch screen, or with the controller's d-pad and screen when the brain can't be
+//! reached from the queue table.
+//!
+//! While a route is selected, the robot's heading is checked against the route's start pose so
+//! the drive team finds out if it's been placed wrong before the match starts. Routes are also
+//! [checked](Route::check) as they're picked, so a broken route script is caught in the queue
+//! rather than when autonomous starts.
+
+mod controller;
+mod touch;
+
+use core::{cell::RefCell, time::Duration};
+
+use evian::math::Angle;
+use log::{error, info, warn};
+use vexide::{
+    devices::display::TouchState,
+    prelude::{Controller, Display},
+    time::sleep,
+};
+
+use self::{controller::ControllerPicker, touch::TouchList};
+use crate::{
+    logger::console,
+    route::{Route, StartPose},
+    tracking::SharedTracking,
+};
+
+/// How often the touch screen and controller are checked for presses.
+const POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+/// How far the robot's heading can be from the selected route's start heading before the drive
+/// team is warned that it's placed wrong.
+const PLACEMENT_TOLERANCE: Angle = Angle::from_degrees(5.0);
+
+/// Picks one of a robot's routes to run in autonomous.
+pub struct RouteSelector<R: 'static> {
+    routes: &'static [&'static dyn Route<R>],
+    selected: Option<usize>,
+
+    /// The selected route's start pose, looked up once when it's selected since a script route
+    /// reads it from the SD card.
+    start: Option<StartPose>,
+    touch: TouchList,
+    picker: ControllerPicker,
+}
+
+impl<R> RouteSelector<R> {
+    pub const fn new(routes: &'static [&'static dyn Route<R>]) -> Self {
+        Self {
+            routes,
+            selected: None,
+            start: None,
+            touch: TouchList::new(),
+            picker: ControllerPicker::new(),
+        }
+    }
+
+    pub const fn routes(&self) -> &'static [&'static dyn Route<R>] {
+        self.routes
+    }
+
+    /// Returns the selected route, if one has been picked.
+    pub fn selected(&self) -> Option<&'static dyn Route<R>> {
+        self.selected.map(|index| self.routes[index])
+    }
+
+    /// Selects the route at `index` in the route list.
+    pub fn select(&mut self, index: usize) {
+        if let Some(route) = self.routes.get(index) {
+            if self.selected != Some(index) {
+                info!("Selected route `{}`.", route.name());
+
+                if let Err(problem) = route.check() {
+                    error!("`{}` can't run: {}", route.name(), problem);
+                    self.picker.rumble();
+                }
+                self.start = route.start();
+            }
+
+            self.selected = Some(index);
+            self.picker.follow(index);
+        }
+    }
+
+    /// Selects a route by name, returning `false` if there's no route with that name.
+    pub fn select_by_name(&mut self, name: &str) -> bool {
+        match self.routes.iter().position(|route| route.name() == name) {
+            Some(index) => {
+                self.select(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns how far the robot's heading is from the selected route's start heading, if it's
+    /// outside of [`PLACEMENT_TOLERANCE`].
+    pub fn misplacement(&self, tracking: &SharedTracking) -> Option<Angle> {
+        let error = self.start?.heading_error(tracking);
+
+        (error.as_radians().abs() > PLACEMENT_TOLERANCE.as_radians()).then_some(error)
+    }
+
+    /// Shows the route list on the brain's display and the controller's screen, selecting routes
+    /// as they're picked on either.
+    ///
+    /// `tracking`'s heading is compared to the selected route's start heading, and the controller
+    /// warns the drive team if the robot is placed wrong.
+    ///
+    /// This never returns, so it should be run from `Compete::disabled`, which is cancelled when
+    /// the match starts. The log console is hidden for as long as the list is shown.
+    pub async fn run(
+        &mut self,
+        display: &RefCell<Display>,
+        controller: &mut Controller,
+        tracking: &SharedTracking,
+    ) {
+        let _console = HiddenConsole::new();
+        let mut dirty = true;
+        let mut was_pressed = false;
+        let mut warned = None;
+        self.picker.redraw();
+
+        loop {
+            if let Ok(display) = display.try_borrow() {
+                let touch = display.touch_status();
+                let pressed = touch.state != TouchState::Released;
+
+                if pressed && !was_pressed {
+                    if let Some(index) = self.touch.press(self.routes, touch.y) {
+                        self.select(index);
+                    }
+                    dirty = true;
+                }
+                was_pressed = pressed;
+            }
+
+            if let Some(index) = self.picker.poll(controller, self.routes) {
+                self.select(index);
+                dirty = true;
+            }
+
+            let misplacement = self.misplacement(tracking);
+            match (misplacement, self.selected, self.start) {
+                (Some(error), Some(index), Some(start)) if warned != Some(index) => {
+                    warn!(
+                        "Robot is {:.0}° off `{}`'s start heading ({:.0}°).",
+                        error.as_degrees(),
+                        self.routes[index].name(),
+                        start.heading.as_degrees(),
+                    );
+                    self.picker.rumble();
+                    warned = Some(index);
+                }
+                (None, ..) => warned = None,
+                _ => {}
+            }
+
+            if dirty {
+                if let Ok(mut display) = display.try_borrow_mut() {
+                    self.touch.draw(&mut display, self.routes, self.selected);
+                    dirty = false;
+                }
+            }
+            self.picker
+                .draw(controller, self.routes, self.selected, misplacement);
+
+            sleep(POLL_INTERVAL).await;
+        }
+    }
+}
+
+/// Keeps the log console hidden until dropped, including when the selector is cancelled.
+struct HiddenConsole;
+
+impl HiddenConsole {
+    fn new() -> Self {
+        console::hide();
+        Self
+    }
+}
+
+impl Drop for HiddenConsole {
+    fn drop(&mut self) {
+        console::show();
+    }
+}
@@ -0,0 +1,129 @@
+//! Route list on the brain's touch screen.
+
+use alloc::format;
+
+use vexide::{
+    devices::display::{Font, FontFamily, FontSize, Rect, Text},
+    prelude::{Display, Rgb},
+};
+
+use crate::{
+    route::Route,
+    theme::{WAR_EAGLE_BLUE, WAR_EAGLE_ORANGE, WAR_EAGLE_TEXT},
+};
+
+const HEADER_HEIGHT: i16 = 32;
+const ROW_HEIGHT: i16 = 41;
+const STRIPE_WIDTH: i16 = 10;
+const MARGIN: i16 = 8;
+
+/// Number of routes that fit on the screen at once.
+const ROWS_PER_PAGE: usize = ((Display::VERTICAL_RESOLUTION - HEADER_HEIGHT) / ROW_HEIGHT) as usize;
+
+pub(super) struct TouchList {
+    page: usize,
+}
+
+impl TouchList {
+    pub const fn new() -> Self {
+        Self { page: 0 }
+    }
+
+    fn pages<R>(routes: &[&dyn Route<R>]) -> usize {
+        routes.len().div_ceil(ROWS_PER_PAGE).max(1)
+    }
+
+    /// Handles a press at height `y`, returning the index of the route that was tapped.
+    pub fn press<R>(&mut self, routes: &[&dyn Route<R>], y: i16) -> Option<usize> {
+        // The header cycles through pages when there are too many routes to fit.
+        if y < HEADER_HEIGHT {
+            self.page = (self.page + 1) % Self::pages(routes);
+            return None;
+        }
+
+        let row = ((y - HEADER_HEIGHT) / ROW_HEIGHT) as usize;
+        let index = self.page * ROWS_PER_PAGE + row;
+
+        (row < ROWS_PER_PAGE && index < routes.len()).then_some(index)
+    }
+
+    pub fn draw<R>(
+        &self,
+        display: &mut Display,
+        routes: &[&dyn Route<R>],
+        selected: Option<usize>,
+    ) {
+        let name_font = Font::new(FontSize::MEDIUM, FontFamily::Proportional);
+        let small_font = Font::new(FontSize::SMALL, FontFamily::Proportional);
+
+        display.fill(
+            &Rect::new(
+                [0, 0],
+                [Display::HORIZONTAL_RESOLUTION, Display::VERTICAL_RESOLUTION],
+            ),
+            Rgb::new(0, 0, 0),
+        );
+        display.fill(
+            &Rect::new([0, 0], [Display::HORIZONTAL_RESOLUTION, HEADER_HEIGHT]),
+            WAR_EAGLE_ORANGE,
+        );
+        display.draw_text(
+            &Text::new(
+                selected.map_or("Select a route", |index| routes[index].name()),
+                name_font,
+                [MARGIN, 4],
+            ),
+            WAR_EAGLE_TEXT,
+            None,
+        );
+
+        let pages = Self::pages(routes);
+        if pages > 1 {
+            display.draw_text(
+                &Text::new(
+                    &format!("Page {}/{} >", self.page + 1, pages),
+                    small_font,
+                    [Display::HORIZONTAL_RESOLUTION - 100, 8],
+                ),
+                WAR_EAGLE_TEXT,
+                None,
+            );
+        }
+
+        let first = self.page * ROWS_PER_PAGE;
+        for (row, (index, route)) in
+            (0..).zip(routes.iter().enumerate().skip(first).take(ROWS_PER_PAGE))
+        {
+            let top = HEADER_HEIGHT + row * ROW_HEIGHT;
+
+            if selected == Some(index) {
+                display.fill(
+                    &Rect::new(
+                        [0, top],
+                        [Display::HORIZONTAL_RESOLUTION, top + ROW_HEIGHT - 1],
+                    ),
+                    WAR_EAGLE_BLUE,
+                );
+            }
+
+            display.fill(
+                &Rect::new([0, top], [STRIPE_WIDTH, top + ROW_HEIGHT - 1]),
+                route.alliance().color(),
+            );
+            display.draw_text(
+                &Text::new(route.name(), name_font, [STRIPE_WIDTH + MARGIN, top + 2]),
+                WAR_EAGLE_TEXT,
+                None,
+            );
+            display.draw_text(
+                &Text::new(
+                    route.description(),
+                    small_font,
+                    [STRIPE_WIDTH + MARGIN, top + 22],
+                ),
+                WAR_EAGLE_TEXT,
+                None,
+            );
+        }
+    }
+}
@@ -0,0 +1,251 @@
+//! Drivetrain Simulation
+//!
+//! Puts evian tracking on top of the [`drive_sim`] drivetrain model, which stands in for the
+//! real robot when there isn't one on a field. Simulated sensors report what the robot's drive
+//! encoders, tracking wheels and IMU would see:
+//!
+//! ```ignore
+//! let sim = Simulation::new(
+//!     DriveModel::from_rpm(Robot::TRACK_WIDTH, 3.25, 450.0),
+//!     StartPose::from_degrees(10.0, -63.0, 214.0),
+//!     1,
+//! );
+//! let tracking = sim.tracking();
+//!
+//! sim.set_voltages(6.0, 6.0);
+//! sim.advance(Duration::from_secs(1));
+//!
+//! info!("True pose {}, tracked {}", sim.pose(), tracking.pose());
+//! ```
+//!
+//! [`Simulation::tracking`] is a real [`WheeledTracking`] on the simulated sensors, so the
+//! odometry seen in simulation goes through the same code it does on the robot, and it updates
+//! the same way too: on vexide's executor. The model itself lives in the `drive-sim` crate, which
+//! doesn't need vexide at all and is tested on the host.
+//!
+//! Every step is recorded, and [`Simulation::trajectory`] returns the true pose and applied
+//! voltages over the whole run at [`telemetry::INTERVAL`](crate::telemetry::INTERVAL). Samples
+//! convert to the same telemetry records a real run sends with [`Sample::records`].
+//!
+//! This module is only built with the `sim` feature.
+
+mod sensors;
+
+use alloc::{rc::Rc, vec::Vec};
+use core::{cell::RefCell, time::Duration};
+
+use drive_sim::Drive;
+pub use drive_sim::{DriveModel, STEP};
+use evian::{
+    math::{Angle, Vec2},
+    tracking::wheeled::{TrackingWheel, WheeledTracking},
+};
+
+pub use self::sensors::{SimImu, SimWheel};
+use crate::{
+    route::StartPose,
+    telemetry::{Record, INTERVAL},
+    tracking::{Pose, SharedTracking},
+};
+
+/// A point along a simulated run.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sample {
+    /// Time since the simulation started.
+    pub time: Duration,
+
+    /// Where the robot really was, as opposed to where its tracking thought it was.
+    pub pose: Pose,
+
+    /// Voltages applied to the left and right sides.
+    pub voltages: [f64; 2],
+}
+
+impl Sample {
+    /// Converts this sample to the telemetry records the robot would have sent at the same
+    /// point, so simulated runs can be saved and plotted the same way as real ones.
+    pub fn records(&self) -> [Record; 2] {
+        let timestamp = self.time.as_millis() as u32;
+
+        [
+            Record::Pose {
+                timestamp,
+                x: self.pose.position.x as f32,
+                y: self.pose.position.y as f32,
+                heading: self.pose.heading.as_radians() as f32,
+            },
+            Record::Drive {
+                timestamp,
+                left_voltage: self.voltages[0] as f32,
+                right_voltage: self.voltages[1] as f32,
+            },
+        ]
+    }
+}
+
+/// Everything the simulated sensors share.
+struct World {
+    drive: Drive,
+    start: StartPose,
+    trajectory: Vec<Sample>,
+}
+
+impl World {
+    fn sample(&self) -> Sample {
+        let state = self.drive.state();
+
+        Sample {
+            time: self.drive.time(),
+            pose: Pose {
+                position: Vec2::new(state.x, state.y),
+                heading: Angle::from_radians(state.heading),
+            },
+            voltages: self.drive.voltages(),
+        }
+    }
+}
+
+/// A simulated drivetrain. Clones share the same robot.
+#[derive(Clone)]
+pub struct Simulation(Rc<RefCell<World>>);
+
+impl Simulation {
+    /// Creates a stopped robot at `start`. Runs with the same `seed` get the same noise.
+    pub fn new(model: DriveModel, start: StartPose, seed: u64) -> Self {
+        let world = World {
+            drive: Drive::new(
+                model,
+                start.position.x,
+                start.position.y,
+                start.heading.as_radians(),
+                seed,
+            ),
+            start,
+            trajectory: Vec::new(),
+        };
+        let sample = world.sample();
+        let sim = Self(Rc::new(RefCell::new(world)));
+
+        sim.0.borrow_mut().trajectory.push(sample);
+        sim
+    }
+
+    pub fn model(&self) -> DriveModel {
+        *self.0.borrow().drive.model()
+    }
+
+    /// Sets the voltages applied to the left and right sides, which are held until they're set
+    /// again.
+    pub fn set_voltages(&self, left: f64, right: f64) {
+        self.0.borrow_mut().drive.set_voltages([left, right]);
+    }
+
+    pub fn voltages(&self) -> [f64; 2] {
+        self.0.borrow().drive.voltages()
+    }
+
+    /// Advances the simulation by a single step of `dt`.
+    pub fn step(&self, dt: Duration) {
+        let mut world = self.0.borrow_mut();
+        world.drive.step(dt);
+
+        let last = world
+            .trajectory
+            .last()
+            .map_or(Duration::ZERO, |sample| sample.time);
+        if world.drive.time() - last >= INTERVAL {
+            let sample = world.sample();
+            world.trajectory.push(sample);
+        }
+    }
+
+    /// Advances the simulation by `duration` in steps of [`STEP`].
+    pub fn advance(&self, duration: Duration) {
+        let end = self.time() + duration;
+
+        while self.time() < end {
+            self.step(STEP.min(end - self.time()));
+        }
+    }
+
+    /// Time since the simulation started.
+    pub fn time(&self) -> Duration {
+        self.0.borrow().drive.time()
+    }
+
+    /// Returns where the robot really is.
+    pub fn pose(&self) -> Pose {
+        self.0.borrow().sample().pose
+    }
+
+    /// Returns the recorded run so far, including the current pose.
+    pub fn trajectory(&self) -> Vec<Sample> {
+        let world = self.0.borrow();
+        let mut trajectory = world.trajectory.clone();
+
+        if trajectory
+            .last()
+            .is_some_and(|last| last.time != world.drive.time())
+        {
+            trajectory.push(world.sample());
+        }
+
+        trajectory
+    }
+
+    /// A sensor on the left drive motors, as used for motor-encoder odometry.
+    pub fn left_drive_wheel(&self) -> SimWheel {
+        SimWheel::left_drive(self.0.clone(), self.model().wheel_diameter)
+    }
+
+    /// A sensor on the right drive motors.
+    pub fn right_drive_wheel(&self) -> SimWheel {
+        SimWheel::right_drive(self.0.clone(), self.model().wheel_diameter)
+    }
+
+    /// An unpowered tracking wheel parallel to the drivetrain, `offset` inches to the right of
+    /// center. Unlike the drive wheels, it doesn't see slip.
+    pub fn tracking_wheel(&self, diameter: f64, offset: f64) -> SimWheel {
+        SimWheel::forward(self.0.clone(), diameter, offset)
+    }
+
+    /// An unpowered tracking wheel perpendicular to the drivetrain, `offset` inches in front of
+    /// center.
+    pub fn sideways_wheel(&self, diameter: f64, offset: f64) -> SimWheel {
+        SimWheel::sideways(self.0.clone(), diameter, offset)
+    }
+
+    pub fn imu(&self) -> SimImu {
+        SimImu::new(self.0.clone())
+    }
+
+    /// Builds odometry from the drive motors and IMU, the way the robots track, starting from
+    /// the simulation's start pose.
+    pub fn tracking(&self) -> SharedTracking {
+        let (start, model) = {
+            let world = self.0.borrow();
+            (world.start, *world.drive.model())
+        };
+
+        SharedTracking::new(WheeledTracking::new(
+            start.position,
+            start.heading,
+            [
+                TrackingWheel::new(
+                    self.left_drive_wheel(),
+                    model.wheel_diameter,
+                    -model.track_width / 2.0,
+                    None,
+                ),
+                TrackingWheel::new(
+                    self.right_drive_wheel(),
+                    model.wheel_diameter,
+                    model.track_width / 2.0,
+                    None,
+                ),
+            ],
+            [] as [TrackingWheel<SimWheel>; 0],
+            Some(self.imu()),
+        ))
+    }
+}
@@ -0,0 +1,116 @@
+use alloc::rc::Rc;
+use core::{cell::RefCell, convert::Infallible, f64::consts::PI};
+
+use evian::{
+    math::Angle,
+    tracking::{Gyro, RotarySensor},
+};
+use vexide::prelude::Position;
+
+use super::World;
+
+/// Where a [`SimWheel`] is mounted, which decides what travel it sees.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Mount {
+    /// On the left side's drive motors, turning with the powered wheels whether or not they
+    /// slip.
+    LeftDrive,
+
+    /// On the right side's drive motors.
+    RightDrive,
+
+    /// An unpowered wheel parallel to the drivetrain, `offset` inches to the right of center.
+    Forward { offset: f64 },
+
+    /// An unpowered wheel perpendicular to the drivetrain, `offset` inches in front of center.
+    Sideways { offset: f64 },
+}
+
+/// A simulated rotary sensor measuring the travel of a wheel.
+///
+/// Pass it to [`TrackingWheel::new`](evian::tracking::wheeled::TrackingWheel::new) with the
+/// same diameter and offset it was created with, and no gearing.
+#[derive(Clone)]
+pub struct SimWheel {
+    world: Rc<RefCell<World>>,
+    mount: Mount,
+    diameter: f64,
+}
+
+impl SimWheel {
+    pub(super) fn left_drive(world: Rc<RefCell<World>>, diameter: f64) -> Self {
+        Self::new(world, Mount::LeftDrive, diameter)
+    }
+
+    pub(super) fn right_drive(world: Rc<RefCell<World>>, diameter: f64) -> Self {
+        Self::new(world, Mount::RightDrive, diameter)
+    }
+
+    pub(super) fn forward(world: Rc<RefCell<World>>, diameter: f64, offset: f64) -> Self {
+        Self::new(world, Mount::Forward { offset }, diameter)
+    }
+
+    pub(super) fn sideways(world: Rc<RefCell<World>>, diameter: f64, offset: f64) -> Self {
+        Self::new(world, Mount::Sideways { offset }, diameter)
+    }
+
+    fn new(world: Rc<RefCell<World>>, mount: Mount, diameter: f64) -> Self {
+        Self {
+            world,
+            mount,
+            diameter,
+        }
+    }
+
+    /// Distance the wheel's surface has travelled, in inches.
+    pub fn travel(&self) -> f64 {
+        let state = *self.world.borrow().drive.state();
+
+        match self.mount {
+            Mount::LeftDrive => state.wheel_travel[0],
+            Mount::RightDrive => state.wheel_travel[1],
+            // A wheel off to the side of the center of rotation sweeps an arc as the robot turns.
+            Mount::Forward { offset } => state.forward_travel + state.rotation * offset,
+            Mount::Sideways { offset } => state.rotation * offset,
+        }
+    }
+}
+
+impl RotarySensor for SimWheel {
+    type Error = Infallible;
+
+    fn position(&self) -> Result<Position, Self::Error> {
+        Ok(Position::from_degrees(
+            self.travel() / (PI * self.diameter) * 360.0,
+        ))
+    }
+}
+
+/// A simulated inertial sensor.
+///
+/// Like the real sensor, it reports rotation since it was calibrated (counterclockwise, to match
+/// [`ScaledImu`](crate::hardware::ScaledImu)), along with the model's drift and noise.
+#[derive(Clone)]
+pub struct SimImu {
+    world: Rc<RefCell<World>>,
+}
+
+impl SimImu {
+    pub(super) fn new(world: Rc<RefCell<World>>) -> Self {
+        Self { world }
+    }
+}
+
+impl Gyro for SimImu {
+    type Error = Infallible;
+
+    fn heading(&self) -> Result<Angle, Self::Error> {
+        Ok(Angle::from_radians(
+            self.world.borrow_mut().drive.imu_rotation(),
+        ))
+    }
+
+    fn angular_velocity(&self) -> Result<f64, Self::Error> {
+        Ok(self.world.borrow().drive.imu_rate())
+    }
+}
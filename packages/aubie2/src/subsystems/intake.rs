@@ -7,11 +7,13 @@ use core::{
     time::Duration,
 };
 
-use log::info;
+use log::{info, warn};
 use vexide::{
     devices::PortError, io::println, prelude::{sleep, spawn, AdiDigitalOut, BrakeMode, Motor, OpticalSensor, SmartDevice, Task}, time::Instant
 };
 
+use crate::telemetry::{self, Record};
+
 /// Intake Rejection Color
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum RingColor {
@@ -62,6 +64,8 @@ impl Intake {
                 let mut jam_timestamp = Instant::now();
                 let mut jammed = false;
 
+                let mut telemetry_timestamp = Instant::now();
+
                 loop {
                     let top_voltage = top_voltage.load(Ordering::Acquire) as f64;
                     let bottom_voltage = bottom_voltage.load(Ordering::Acquire) as f64;
@@ -147,6 +151,7 @@ impl Intake {
                         }
     
                         if !jammed && jam_timestamp.elapsed() > Duration::from_millis(500) {
+                            warn!("Intake jam, reversing.");
                             jammed = true;
                         } else if jammed && jam_timestamp.elapsed() > Duration::from_millis(1000) {
                             jammed = false;
@@ -157,6 +162,19 @@ impl Intake {
                         _ = motor.set_voltage(bottom_voltage);
                     }
 
+                    if telemetry::is_recording()
+                        && telemetry_timestamp.elapsed() > telemetry::INTERVAL
+                    {
+                        telemetry::send(Record::Intake {
+                            timestamp: telemetry::timestamp(),
+                            top_voltage: top_voltage as f32,
+                            bottom_voltage: bottom_voltage as f32,
+                            rejecting,
+                            jammed,
+                        });
+                        telemetry_timestamp = Instant::now();
+                    }
+
                     sleep(OpticalSensor::UPDATE_INTERVAL).await;
                 }
             }),
@@ -2,7 +2,7 @@ use alloc::rc::Rc;
 use core::{cell::RefCell, time::Duration};
 
 use evian::control::loops::Feedback;
-use log::warn;
+use log::{error, info};
 use vexide::{
     devices::{
         position::Position,
@@ -12,9 +12,11 @@ use vexide::{
         },
     },
     task::{spawn, Task},
-    time::sleep,
+    time::{sleep, Instant},
 };
 
+use crate::telemetry::{self, Record};
+
 /// Lady brown wallstake mechanism.
 pub struct LadyBrown {
     target: Rc<RefCell<LadyBrownTarget>>,
@@ -34,10 +36,18 @@ impl LadyBrown {
         Self {
             target: target.clone(),
             _task: spawn(async move {
+                let mut telemetry_timestamp = Instant::now();
+                let mut sensor_lost = false;
+
                 loop {
                     // debug!("{:?}", rotation_sensor.position().unwrap().as_degrees());
                     match rotation_sensor.position() {
                         Ok(position) => {
+                            if sensor_lost {
+                                info!("LB sensor recovered.");
+                                sensor_lost = false;
+                            }
+
                             let motor_target = match *target.borrow() {
                                 LadyBrownTarget::Position(state) => {
                                     MotorControl::Voltage(feedback.update(
@@ -52,9 +62,30 @@ impl LadyBrown {
                             for motor in motors.iter_mut() {
                                 _ = motor.set_target(motor_target);
                             }
+
+                            if telemetry::is_recording()
+                                && telemetry_timestamp.elapsed() > telemetry::INTERVAL
+                            {
+                                telemetry::send(Record::LadyBrown {
+                                    timestamp: telemetry::timestamp(),
+                                    angle: position.as_degrees() as f32,
+                                    target: match *target.borrow() {
+                                        LadyBrownTarget::Position(state) => {
+                                            Some(state.as_degrees() as f32)
+                                        }
+                                        LadyBrownTarget::Manual(_) => None,
+                                    },
+                                });
+                                telemetry_timestamp = Instant::now();
+                            }
                         }
+                        // Only report the transition, since this fails every loop until the
+                        // sensor is plugged back in.
                         Err(err) => {
-                            warn!("{err}");
+                            if !sensor_lost {
+                                error!("LB sensor lost: {err}");
+                                sensor_lost = true;
+                            }
                         }
                     }
 
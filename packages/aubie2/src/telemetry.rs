@@ -0,0 +1,145 @@
+//! Binary Telemetry
+//!
+//! Records are framed with the [`telemetry`] crate's protocol and written to serial alongside
+//! the text log. Sending is disabled by default, since the frames show up as garbage in a plain
+//! terminal. It can be turned on with [`enable`] or by sending `telemetry on` over serial, and
+//! captured streams can be converted to CSV with the `telemetry-decode` host tool.
+//!
+//! Records are always saved to the SD card log while it's enabled, so the pose trace of any run
+//! can be recovered from the card afterwards, such as with the `trajectory-svg` host tool.
+
+use alloc::{rc::Rc, vec::Vec};
+use core::{
+    cell::RefCell,
+    sync::atomic::{AtomicBool, Ordering},
+    time::Duration,
+};
+
+pub use ::telemetry::Record;
+use ::telemetry::MAX_FRAME_SIZE;
+use evian::tracking::{TracksHeading, TracksPosition};
+use vexide::{prelude::Motor, sync::Mutex, task::spawn, time::sleep};
+
+use crate::{logger::sd_card, tracking::SharedTracking};
+
+/// How often subsystems sample their state.
+pub const INTERVAL: Duration = Duration::from_millis(20);
+
+/// Encoded bytes past which new records are dropped until serial catches up.
+const MAX_PENDING_BYTES: usize = 4 * 1024;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static PENDING: Mutex<Vec<u8>> = Mutex::new(Vec::new());
+
+pub fn enable() {
+    ENABLED.store(true, Ordering::Release);
+}
+
+pub fn disable() {
+    ENABLED.store(false, Ordering::Release);
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Acquire)
+}
+
+/// Returns `true` if records are being saved anywhere, either to serial or the SD card.
+pub fn is_recording() -> bool {
+    is_enabled() || sd_card::is_enabled()
+}
+
+/// Milliseconds since program start, for use as a record timestamp.
+pub fn timestamp() -> u32 {
+    (unsafe { vex_sdk::vexSystemHighResTimeGet() } / 1000) as u32
+}
+
+/// Queues a record to be written to serial by the logger's drain task, and to the SD card log.
+pub fn send(record: Record) {
+    if !is_recording() {
+        return;
+    }
+
+    let mut buf = [0; MAX_FRAME_SIZE];
+    let frame = record.encode(&mut buf);
+
+    sd_card::write_frame(frame);
+
+    if !is_enabled() {
+        return;
+    }
+
+    let Some(mut pending) = PENDING.try_lock() else {
+        return;
+    };
+
+    if pending.len() + frame.len() <= MAX_PENDING_BYTES {
+        pending.extend_from_slice(frame);
+    }
+}
+
+/// Writes as many queued bytes as serial will currently accept.
+pub(crate) fn drain() {
+    let Some(mut pending) = PENDING.try_lock() else {
+        return;
+    };
+
+    if pending.is_empty() {
+        return;
+    }
+
+    let written =
+        unsafe { vex_sdk::vexSerialWriteBuffer(1, pending.as_ptr(), pending.len() as u32) };
+
+    if written > 0 {
+        pending.drain(..written as usize);
+    }
+}
+
+/// Starts a background task sending the drivetrain's pose and motor voltages.
+pub fn start_drivetrain_sampler<const LEFT: usize, const RIGHT: usize>(
+    tracking: SharedTracking,
+    left_motors: Rc<RefCell<[Motor; LEFT]>>,
+    right_motors: Rc<RefCell<[Motor; RIGHT]>>,
+) {
+    spawn(async move {
+        loop {
+            if is_recording() {
+                let timestamp = timestamp();
+                let position = tracking.position();
+
+                send(Record::Pose {
+                    timestamp,
+                    x: position.x as f32,
+                    y: position.y as f32,
+                    heading: tracking.heading().as_radians() as f32,
+                });
+                send(Record::Drive {
+                    timestamp,
+                    left_voltage: average_voltage(&*left_motors.borrow()),
+                    right_voltage: average_voltage(&*right_motors.borrow()),
+                });
+            }
+
+            sleep(INTERVAL).await;
+        }
+    })
+    .detach();
+}
+
+fn average_voltage(motors: &[Motor]) -> f32 {
+    let mut sum = 0.0;
+    let mut total = 0;
+
+    for motor in motors {
+        if let Ok(voltage) = motor.voltage() {
+            sum += voltage;
+            total += 1;
+        }
+    }
+
+    if total > 0 {
+        (sum / f64::from(total)) as f32
+    } else {
+        0.0
+    }
+}
@@ -1,5 +1,8 @@
+use vexide::prelude::Rgb;
+#[cfg(target_vendor = "vex")]
 use vexide::startup::banner::themes::BannerTheme;
 
+#[cfg(target_vendor = "vex")]
 #[expect(
     edition_2024_expr_fragment_specifier,
     reason = "OK for this macro to accept `const {}` expressions"
@@ -10,6 +13,8 @@ macro_rules! ansi_rgb_bold {
     };
 }
 
+/// Startup banner colors. The banner is only printed on the brain.
+#[cfg(target_vendor = "vex")]
 pub const THEME_WAR_EAGLE: BannerTheme = BannerTheme {
     emoji: "🦅",
     logo_primary: [
@@ -25,3 +30,12 @@ pub const THEME_WAR_EAGLE: BannerTheme = BannerTheme {
     crate_version: ansi_rgb_bold!(246, 88, 12),
     metadata_key: ansi_rgb_bold!(45, 105, 194),
 };
+
+/// Primary color of [`THEME_WAR_EAGLE`], for drawing on the brain's display.
+pub const WAR_EAGLE_ORANGE: Rgb<u8> = Rgb::new(246, 88, 12);
+
+/// Secondary color of [`THEME_WAR_EAGLE`], for drawing on the brain's display.
+pub const WAR_EAGLE_BLUE: Rgb<u8> = Rgb::new(45, 105, 194);
+
+/// Text color of [`THEME_WAR_EAGLE`], for drawing on the brain's display.
+pub const WAR_EAGLE_TEXT: Rgb<u8> = Rgb::new(228, 228, 228);
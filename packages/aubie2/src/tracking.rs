@@ -0,0 +1,105 @@
+use alloc::rc::Rc;
+use core::{
+    cell::RefCell,
+    f64::consts::{PI, TAU},
+    fmt,
+};
+
+use evian::{
+    math::{Angle, Vec2},
+    tracking::{
+        wheeled::WheeledTracking, TracksForwardTravel, TracksHeading, TracksPosition,
+        TracksVelocity,
+    },
+};
+
+/// Odometry that can be read from outside of the drivetrain that owns it.
+///
+/// Motions hold a mutable borrow of the drivetrain for as long as they're running, so anything
+/// that needs to watch the robot's pose during a motion (telemetry, triggers, displays) gets a
+/// clone of this instead.
+#[derive(Clone)]
+pub struct SharedTracking(Rc<RefCell<WheeledTracking>>);
+
+impl SharedTracking {
+    pub fn new(tracking: WheeledTracking) -> Self {
+        Self(Rc::new(RefCell::new(tracking)))
+    }
+
+    pub fn set_heading(&self, heading: Angle) {
+        self.0.borrow_mut().set_heading(heading);
+    }
+
+    pub fn set_position(&self, position: Vec2<f64>) {
+        self.0.borrow_mut().set_position(position);
+    }
+
+    /// Returns the current position and heading.
+    pub fn pose(&self) -> Pose {
+        let tracking = self.0.borrow();
+
+        Pose {
+            position: tracking.position(),
+            heading: tracking.heading(),
+        }
+    }
+}
+
+/// A snapshot of the robot's tracked position (in inches) and heading.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Pose {
+    pub position: Vec2<f64>,
+    pub heading: Angle,
+}
+
+impl fmt::Display for Pose {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "({:.1}, {:.1}) {:.0}°",
+            self.position.x,
+            self.position.y,
+            self.heading.as_degrees()
+        )
+    }
+}
+
+impl TracksPosition for SharedTracking {
+    fn position(&self) -> Vec2<f64> {
+        self.0.borrow().position()
+    }
+}
+
+impl TracksHeading for SharedTracking {
+    fn heading(&self) -> Angle {
+        self.0.borrow().heading()
+    }
+}
+
+impl TracksVelocity for SharedTracking {
+    fn linear_velocity(&self) -> f64 {
+        self.0.borrow().linear_velocity()
+    }
+
+    fn angular_velocity(&self) -> f64 {
+        self.0.borrow().angular_velocity()
+    }
+}
+
+impl TracksForwardTravel for SharedTracking {
+    fn forward_travel(&self) -> f64 {
+        self.0.borrow().forward_travel()
+    }
+}
+
+/// Wraps an angle in radians to the range `(-π, π]`.
+pub(crate) fn wrap(mut angle: f64) -> f64 {
+    while angle > PI {
+        angle -= TAU;
+    }
+    while angle <= -PI {
+        angle += TAU;
+    }
+
+    angle
+}
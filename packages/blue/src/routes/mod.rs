@@ -1,3 +1,41 @@
+use aubie2::{route::Route, routes};
+
+use crate::Robot;
+
 pub mod blue;
 pub mod red;
-pub mod skills;
\ No newline at end of file
+pub mod script;
+pub mod skills;
+
+/// Routes listed in the selector, in the order they're shown.
+pub const ROUTES: &[&dyn Route<Robot>] = routes![Robot;
+    "Red" {
+        alliance: Red,
+        description: "Goal rush, clamp, stacks",
+        start: (-31.0, -44.0, 120.0),
+        waypoints: Robot::red_waypoints,
+        run: Robot::red,
+    },
+    "Blue" {
+        alliance: Blue,
+        description: "Goal rush, clamp, stacks",
+        start: (30.0, -41.0, 60.0),
+        waypoints: Robot::blue_waypoints,
+        run: Robot::blue,
+    },
+    "Skills" {
+        alliance: Red,
+        description: "Programming skills",
+        start: (0.0, -62.0, 270.0),
+        period: 60,
+        run: Robot::skills,
+    },
+    "Red Script" {
+        alliance: Red,
+        description: "Runs route.txt from the SD card",
+        start_from: aubie2::route::script::script_start::<Robot>,
+        check: aubie2::route::script::check_script::<Robot>,
+        run: aubie2::route::script::run_script::<Robot>,
+        mirrored: "Blue Script",
+    },
+];
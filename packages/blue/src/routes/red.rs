@@ -1,171 +1,290 @@
+use alloc::vec::Vec;
 use core::time::Duration;
 
+use aubie2::route::Triggers;
 use evian::{
-    math::IntoAngle,
+    math::{IntoAngle, Vec2},
     motion::{Basic, Seeking},
 };
-use vexide::time::sleep;
 
 use crate::Robot;
 
-// practice: 
+const RUSH: (f64, f64) = (-42.0, -19.0);
+const STACK_TOP: (f64, f64) = (-50.5, -29.0);
+const RING: (f64, f64) = (-30.0, -33.0);
+const STACK: (f64, f64) = (-47.0, -51.5);
+const CORNER: (f64, f64) = (-65.0, -73.0);
+const ALLIANCE_STAKE: (f64, f64) = (0.0, -62.0);
+const DARTS: (f64, f64) = (0.0, -59.0);
+
+// practice:
 impl Robot {
-    pub async fn red(&mut self) {
-        self.drivetrain.tracking.set_heading(120.0.deg());
+    /// Points [`Robot::red`] drives to, for the field map.
+    pub fn red_waypoints() -> Vec<Vec2<f64>> {
+        [RUSH, STACK_TOP, RING, STACK, CORNER, ALLIANCE_STAKE, DARTS]
+            .into_iter()
+            .map(Vec2::from)
+            .collect()
+    }
 
+    pub async fn red(&mut self) {
         let dt = &mut self.drivetrain;
+        let tl = &mut self.timeline;
+        // Motions are built without timeouts, since the timeline stops them.
+        let timeout = Some(Duration::from_secs(5));
         let mut basic = Basic {
             linear_controller: Robot::LINEAR_PID,
             angular_controller: Robot::ANGUALR_PID,
             linear_tolerances: Robot::LINEAR_TOLERANCES,
             angular_tolerances: Robot::ANGULAR_TOLERANCES,
-            timeout: Some(Duration::from_secs(5)),
+            timeout: None,
         };
         let mut seeking = Seeking {
             linear_controller: Robot::LINEAR_PID,
             angular_controller: Robot::ANGUALR_PID,
             tolerances: Robot::LINEAR_TOLERANCES,
-            timeout: Some(Duration::from_secs(5)),
+            timeout: None,
         };
 
         // Goal Rush
-        _ = self.left_arm.set_high();
-        seeking
-            .move_to_point(dt, (-11.0, 25.0))
-            .with_linear_kp(2.0)
-            .without_tolerance_duration()
-            .await;
-        _ = self.pinchers.set_high();
+        tl.action("Arm down", || _ = self.left_arm.set_high());
+        tl.motion(
+            "Rush",
+            timeout,
+            seeking
+                .move_to_point(dt, RUSH)
+                .with_linear_kp(2.0)
+                .without_tolerance_duration(),
+        )
+        .await;
+        tl.checkpoint("Rush", RUSH, None);
+        tl.action("Pinch", || _ = self.pinchers.set_high());
 
         // Drag back, unpinch.
-        basic
-            .drive_distance_at_heading(dt, -24.0, 70.0.deg())
-            .with_timeout(Duration::from_secs_f64(1.5))
-            .await;
-        _ = self.pinchers.set_low();
-        basic.drive_distance_at_heading(dt, 6.0, 70.0.deg())
-            .await;
+        tl.motion(
+            "Drag goal",
+            Some(Duration::from_secs_f64(1.5)),
+            basic.drive_distance_at_heading(dt, -24.0, 70.0.deg()),
+        )
+        .await;
+        tl.action("Unpinch", || _ = self.pinchers.set_low());
+        tl.motion(
+            "Clear goal",
+            timeout,
+            basic.drive_distance_at_heading(dt, 6.0, 70.0.deg()),
+        )
+        .await;
 
-        basic
-            .drive_distance_at_heading(dt, -4.0, 80.0.deg())
-            .with_timeout(Duration::from_millis(500))
-            .await;
-        _ = self.left_arm.set_low();
-        sleep(Duration::from_millis(500)).await;
+        tl.motion(
+            "Back off goal",
+            Some(Duration::from_millis(500)),
+            basic.drive_distance_at_heading(dt, -4.0, 80.0.deg()),
+        )
+        .await;
+        tl.action("Arm up", || _ = self.left_arm.set_low());
+        tl.wait("Arm up", Duration::from_millis(500)).await;
 
         // Clamp
         let goal_angle = 273.0.deg();
-        basic.turn_to_heading(dt, goal_angle).await;
-        basic
-            .drive_distance_at_heading(dt, -22.0, goal_angle)
-            .with_linear_output_limit(4.0)
+        tl.motion("Face goal", timeout, basic.turn_to_heading(dt, goal_angle))
             .await;
-        _ = self.clamp.set_high();
+        tl.motion(
+            "Drive into goal",
+            timeout,
+            basic
+                .drive_distance_at_heading(dt, -22.0, goal_angle)
+                .with_linear_output_limit(4.0),
+        )
+        .await;
+        tl.action("Clamp", || _ = self.clamp.set_high());
 
-        sleep(Duration::from_millis(250)).await;
-        self.intake.set_top_voltage(12.0);
-        sleep(Duration::from_millis(500)).await;
+        tl.wait("Clamp", Duration::from_millis(250)).await;
+        tl.action("Score preload", || self.intake.set_top_voltage(12.0));
+        tl.wait("Score preload", Duration::from_millis(500)).await;
 
         // Top of stack
-        self.intake.set_voltage(12.0);
-        _ = self.intake.raise();
+        tl.action("Raise intake", || {
+            self.intake.set_voltage(12.0);
+            _ = self.intake.raise();
+        });
 
-        basic.turn_to_heading(dt, 200.0.deg()).await;
-        seeking.move_to_point(dt, (-19.5, 15.0)).await;
+        tl.motion(
+            "Face stack",
+            timeout,
+            basic.turn_to_heading(dt, 200.0.deg()),
+        )
+        .await;
+        tl.motion(
+            "Top of stack",
+            timeout,
+            seeking.move_to_point(dt, STACK_TOP),
+        )
+        .await;
+        tl.checkpoint("Top of stack", STACK_TOP, None);
 
-        _ = self.intake.lower();
-        basic.drive_distance(dt, -8.0).await;
+        tl.action("Lower intake", || _ = self.intake.lower());
+        tl.motion("Back from stack", timeout, basic.drive_distance(dt, -8.0))
+            .await;
 
         // Final Path
-        basic.turn_to_heading(dt, 315.0.deg()).await;
-        self.intake.set_bottom_voltage(-12.0); // avoid intaking blue ring
-        seeking.move_to_point(dt, (1.0, 11.0)).await;
+        tl.motion("Face ring", timeout, basic.turn_to_heading(dt, 315.0.deg()))
+            .await;
+        tl.action("Bottom intake out", || {
+            self.intake.set_bottom_voltage(-12.0); // avoid intaking blue ring
+        });
+        tl.motion("Ring", timeout, seeking.move_to_point(dt, RING))
+            .await;
+        tl.checkpoint("Ring", RING, None);
 
-        basic.turn_to_heading(dt, 225.0.deg()).await;
-        self.intake.set_bottom_voltage(12.0);
+        tl.motion(
+            "Face stack",
+            timeout,
+            basic.turn_to_heading(dt, 225.0.deg()),
+        )
+        .await;
+        tl.action("Bottom intake in", || self.intake.set_bottom_voltage(12.0));
 
-        seeking
-            .move_to_point(dt, (-16.0, -7.5))
-            .with_linear_output_limit(3.0)
-            .await;
+        tl.motion(
+            "Stack",
+            timeout,
+            seeking
+                .move_to_point(dt, STACK)
+                .with_linear_output_limit(3.0),
+        )
+        .await;
+        tl.checkpoint("Stack", STACK, None);
 
         // Clear the ring
-        sleep(Duration::from_millis(400)).await;
-        self.intake.set_bottom_voltage(-12.0);
-        basic
-            .turn_to_heading(dt, 305.0.deg())
-            .without_tolerance_duration()
-            .await;
-        _ = self.right_arm.set_high();
-        sleep(Duration::from_millis(250)).await;
+        tl.wait("Stack", Duration::from_millis(400)).await;
+        tl.action("Bottom intake out", || {
+            self.intake.set_bottom_voltage(-12.0)
+        });
+        tl.motion(
+            "Sweep out",
+            timeout,
+            basic
+                .turn_to_heading(dt, 305.0.deg())
+                .without_tolerance_duration(),
+        )
+        .await;
+        tl.action("Arm down", || _ = self.right_arm.set_high());
+        tl.wait("Arm down", Duration::from_millis(250)).await;
 
-        basic
-            .turn_to_heading(dt, 210.0.deg())
-            .without_tolerance_duration()
-            .await;
+        tl.motion(
+            "Sweep ring",
+            timeout,
+            basic
+                .turn_to_heading(dt, 210.0.deg())
+                .without_tolerance_duration(),
+        )
+        .await;
 
-        basic.turn_to_heading(dt, 225.0.deg()).await;
+        tl.motion(
+            "Face corner",
+            timeout,
+            basic.turn_to_heading(dt, 225.0.deg()),
+        )
+        .await;
 
-        _ = self.right_arm.set_low();
-        self.intake.set_voltage(0.0);
+        tl.action("Arm up", || {
+            _ = self.right_arm.set_low();
+            self.intake.set_voltage(0.0);
+        });
 
         // Corner
-        self.lady_brown.set_target(Self::LADY_BROWN_FLAT);
-        seeking
-            .move_to_point(dt, (-34.0, -29.0))
-            .with_linear_output_limit(4.0)
-            .with_timeout(Duration::from_secs(3))
-            .await;
+        tl.action("Lady brown flat", || {
+            self.lady_brown.set_target(Self::LADY_BROWN_FLAT);
+        });
+        tl.motion(
+            "Corner",
+            Some(Duration::from_secs(3)),
+            seeking
+                .move_to_point(dt, CORNER)
+                .with_linear_output_limit(4.0),
+        )
+        .await;
+        tl.checkpoint("Corner", CORNER, None);
 
-        self.intake.set_voltage(12.0);
-        sleep(Duration::from_millis(1000)).await;
+        tl.action("Intake on", || self.intake.set_voltage(12.0));
+        tl.wait("Corner", Duration::from_millis(1000)).await;
 
-        basic.drive_distance(dt, -14.0).await;
-        basic.drive_distance(dt, 13.0)
-            .with_linear_output_limit(6.0).await;
-        basic.drive_distance(dt, -15.0).await;
-        self.intake.set_voltage(-1.0);
-        self.lady_brown.set_target(Self::LADY_BROWN_LOWERED);
+        tl.motion("Back from corner", timeout, basic.drive_distance(dt, -14.0))
+            .await;
+        tl.motion(
+            "Corner again",
+            timeout,
+            basic.drive_distance(dt, 13.0).with_linear_output_limit(6.0),
+        )
+        .await;
+        tl.motion("Back from corner", timeout, basic.drive_distance(dt, -15.0))
+            .await;
+        tl.action("Lower lady brown", || {
+            self.intake.set_voltage(-1.0);
+            self.lady_brown.set_target(Self::LADY_BROWN_LOWERED);
+        });
 
         // Alliance stake
-        basic.turn_to_heading(dt, 0.0.deg()).await;
-        self.intake.set_voltage(12.0);
-
-        futures::join!(
-            async {
-                sleep(Duration::from_millis(800)).await;
-                self.intake.disable_jam_prevention();
-                self.lady_brown.set_target(Self::LADY_BROWN_RAISED);
-            },
-            async {
-                seeking
-                    .move_to_point(dt, (31.0, -18.0))
-                    .with_linear_output_limit(6.0)
-                    .await;
-            }
-        );
-
-        basic.turn_to_heading(dt, 270.0.deg()).await;
-        sleep(Duration::from_millis(500)).await;
-        self.intake.set_top_voltage(-1.0);
+        tl.motion("Face stake", timeout, basic.turn_to_heading(dt, 0.0.deg()))
+            .await;
+        tl.action("Intake on", || self.intake.set_voltage(12.0));
 
-        // darts
-        seeking
-            .move_to_point(dt, (31.0, -15.0))
-            .reverse()
-            .with_tolerance_duration(Duration::from_millis(50))
+        // Raise the lady brown 800ms into the drive, which is when it was tuned to go up.
+        tl.motion(
+            "Alliance stake",
+            timeout,
+            Triggers::new(&dt.tracking)
+                .after(Duration::from_millis(800), || {
+                    self.intake.disable_jam_prevention();
+                    self.lady_brown.set_target(Self::LADY_BROWN_RAISED);
+                })
+                .run(
+                    seeking
+                        .move_to_point(dt, ALLIANCE_STAKE)
+                        .with_linear_output_limit(6.0),
+                ),
+        )
+        .await;
+        tl.checkpoint("Alliance stake", ALLIANCE_STAKE, None);
+
+        tl.motion("Face wall", timeout, basic.turn_to_heading(dt, 270.0.deg()))
             .await;
+        tl.wait("Face wall", Duration::from_millis(500)).await;
+        tl.action("Top intake out", || self.intake.set_top_voltage(-1.0));
+
+        // darts
+        tl.motion(
+            "Darts",
+            timeout,
+            seeking
+                .move_to_point(dt, DARTS)
+                .reverse()
+                .with_tolerance_duration(Duration::from_millis(50)),
+        )
+        .await;
 
-        self.lady_brown.set_target(Self::LADY_BROWN_FLAT);
-        sleep(Duration::from_secs(1)).await;
+        tl.action("Score stake", || {
+            self.lady_brown.set_target(Self::LADY_BROWN_FLAT);
+        });
+        tl.wait("Score stake", Duration::from_secs(1)).await;
 
         // Go touch
-        basic.drive_distance(dt, -10.0).await;
-        self.lady_brown.set_target(Self::LADY_BROWN_UP);
+        tl.motion("Back from stake", timeout, basic.drive_distance(dt, -10.0))
+            .await;
+        tl.action("Lady brown up", || {
+            self.lady_brown.set_target(Self::LADY_BROWN_UP);
+        });
 
-        basic.turn_to_heading(dt, 90.0.deg()).await;
-        basic.drive_distance(dt, 12.0).await;
+        tl.motion(
+            "Face ladder",
+            timeout,
+            basic.turn_to_heading(dt, 90.0.deg()),
+        )
+        .await;
+        tl.motion("Touch", timeout, basic.drive_distance(dt, 12.0))
+            .await;
 
-        self.lady_brown.set_target(Self::LADY_BROWN_FLAT);
+        tl.action("Lady brown flat", || {
+            self.lady_brown.set_target(Self::LADY_BROWN_FLAT);
+        });
     }
 }
@@ -0,0 +1,53 @@
+use alloc::vec;
+use core::time::Duration;
+
+use aubie2::route::script::{Mechanisms, ScriptContext, ScriptNames, ScriptRobot};
+use evian::motion::{Basic, Seeking};
+
+use crate::Robot;
+
+impl ScriptRobot for Robot {
+    const SCRIPT_NAMES: ScriptNames = ScriptNames {
+        lady_brown_presets: &[
+            ("lowered", Self::LADY_BROWN_LOWERED),
+            ("raised", Self::LADY_BROWN_RAISED),
+            ("up", Self::LADY_BROWN_UP),
+            ("scored", Self::LADY_BROWN_SCORED),
+            ("flat", Self::LADY_BROWN_FLAT),
+        ],
+        pneumatics: &["clamp", "left_arm", "right_arm", "pinchers"],
+        mirrored_pneumatics: &[("left_arm", "right_arm")],
+    };
+
+    fn script_context(&mut self) -> ScriptContext<'_> {
+        ScriptContext {
+            drivetrain: &mut self.drivetrain,
+            timeline: &mut self.timeline,
+            basic: Basic {
+                linear_controller: Robot::LINEAR_PID,
+                angular_controller: Robot::ANGUALR_PID,
+                linear_tolerances: Robot::LINEAR_TOLERANCES,
+                angular_tolerances: Robot::ANGULAR_TOLERANCES,
+                timeout: None,
+            },
+            seeking: Seeking {
+                linear_controller: Robot::LINEAR_PID,
+                angular_controller: Robot::ANGUALR_PID,
+                tolerances: Robot::LINEAR_TOLERANCES,
+                timeout: None,
+            },
+            timeout: Some(Duration::from_secs(5)),
+            mechanisms: Mechanisms {
+                intake: &mut self.intake,
+                lady_brown: &mut self.lady_brown,
+                names: &Self::SCRIPT_NAMES,
+                pneumatics: vec![
+                    &mut self.clamp,
+                    &mut self.left_arm,
+                    &mut self.right_arm,
+                    &mut self.pinchers,
+                ],
+            },
+        }
+    }
+}
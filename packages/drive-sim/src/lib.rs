@@ -0,0 +1,119 @@
+//! Differential Drivetrain Model
+//!
+//! A model of a differential drivetrain that stands in for the real robot when there isn't one
+//! on a field. The model is driven with the same left and right voltages the drivetrain's motors
+//! would get, responds to them with a first-order lag up to its free speed, loses a random
+//! fraction of each wheel's travel to slip, and integrates the result into a true pose:
+//!
+//! ```
+//! use core::time::Duration;
+//!
+//! use drive_sim::{Drive, DriveModel};
+//!
+//! let mut drive = Drive::new(DriveModel::from_rpm(11.5, 3.25, 450.0), 0.0, -62.0, 1.57, 1);
+//!
+//! drive.set_voltages([6.0, 6.0]);
+//! drive.advance(Duration::from_secs(1));
+//!
+//! assert!(drive.state().y > -62.0);
+//! ```
+//!
+//! This crate is plain `no_std` Rust with no vexide dependency, so it builds and tests on a
+//! laptop. `aubie2::sim` puts evian tracking on top of it, and the host vex-sdk under `sim/`
+//! drives it from a robot's real motor and sensor calls.
+
+#![no_std]
+
+mod model;
+mod noise;
+
+use core::time::Duration;
+
+pub use self::{
+    model::{wrap, DriveModel, DriveState},
+    noise::Noise,
+};
+
+/// Time advanced by each physics step in [`Drive::advance`].
+pub const STEP: Duration = Duration::from_millis(1);
+
+/// A simulated drivetrain: the model, its true state, and the voltages applied to it.
+#[derive(Debug, Clone)]
+pub struct Drive {
+    model: DriveModel,
+    state: DriveState,
+    voltages: [f64; 2],
+    time: Duration,
+    noise: Noise,
+}
+
+impl Drive {
+    /// Creates a stopped robot at `(x, y)` facing `heading` radians. Runs with the same `seed`
+    /// get the same noise.
+    pub fn new(model: DriveModel, x: f64, y: f64, heading: f64, seed: u64) -> Self {
+        Self {
+            model,
+            state: DriveState::new(x, y, heading),
+            voltages: [0.0; 2],
+            time: Duration::ZERO,
+            noise: Noise::new(seed),
+        }
+    }
+
+    pub const fn model(&self) -> &DriveModel {
+        &self.model
+    }
+
+    pub const fn state(&self) -> &DriveState {
+        &self.state
+    }
+
+    /// Sets the voltages applied to the left and right sides, which are held until they're set
+    /// again.
+    pub fn set_voltages(&mut self, voltages: [f64; 2]) {
+        self.voltages = voltages;
+    }
+
+    pub const fn voltages(&self) -> [f64; 2] {
+        self.voltages
+    }
+
+    /// Time since the simulation started.
+    pub const fn time(&self) -> Duration {
+        self.time
+    }
+
+    /// Advances the simulation by a single step of `dt`.
+    pub fn step(&mut self, dt: Duration) {
+        self.state.step(
+            &self.model,
+            self.voltages,
+            dt.as_secs_f64(),
+            &mut self.noise,
+        );
+        self.time += dt;
+    }
+
+    /// Advances the simulation by `duration` in steps of [`STEP`].
+    pub fn advance(&mut self, duration: Duration) {
+        let end = self.time + duration;
+
+        while self.time < end {
+            self.step(STEP.min(end - self.time));
+        }
+    }
+
+    /// Rotation an IMU would report, counterclockwise in radians since the simulation started,
+    /// including the model's drift and noise.
+    pub fn imu_rotation(&mut self) -> f64 {
+        let drift = self.model.imu_drift * self.time.as_secs_f64();
+        let noise = self.model.imu_noise * self.noise.gaussian();
+
+        self.state.rotation + drift + noise
+    }
+
+    /// Turn rate an IMU would report, in radians per second.
+    pub fn imu_rate(&self) -> f64 {
+        self.state.angular_velocity + self.model.imu_drift
+    }
+}
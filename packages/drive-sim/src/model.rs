@@ -0,0 +1,150 @@
+use core::f64::consts::{PI, TAU};
+
+use libm::{cos, sin};
+
+use crate::Noise;
+
+/// Physical parameters of a simulated differential drivetrain.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DriveModel {
+    /// Distance between the left and right wheels, in inches.
+    pub track_width: f64,
+
+    /// Diameter of the powered wheels, in inches.
+    pub wheel_diameter: f64,
+
+    /// Speed the wheels settle at with 12 V applied and nothing in the way, in inches per second.
+    pub free_speed: f64,
+
+    /// How quickly each side responds to a change in voltage, in seconds. This lumps together
+    /// the motors' back-EMF and the robot's inertia into a first-order lag, so a side reaches
+    /// about 63% of its new speed after one time constant.
+    pub time_constant: f64,
+
+    /// Standard deviation of the fraction of each wheel's travel lost to slip. Slip only ever
+    /// makes the robot go less far than its wheels turned.
+    pub slip: f64,
+
+    /// Constant error in the IMU's rotation rate, in radians per second.
+    pub imu_drift: f64,
+
+    /// Standard deviation of the noise on each IMU reading, in radians.
+    pub imu_noise: f64,
+}
+
+impl DriveModel {
+    /// Creates a model with typical response, slip and IMU noise for a V5 drivetrain.
+    pub const fn new(track_width: f64, wheel_diameter: f64, free_speed: f64) -> Self {
+        Self {
+            track_width,
+            wheel_diameter,
+            free_speed,
+            time_constant: 0.12,
+            slip: 0.02,
+            imu_drift: 0.0,
+            imu_noise: 0.001,
+        }
+    }
+
+    /// Like [`DriveModel::new`], with the free speed worked out from the wheels' free RPM.
+    pub const fn from_rpm(track_width: f64, wheel_diameter: f64, rpm: f64) -> Self {
+        Self::new(
+            track_width,
+            wheel_diameter,
+            rpm / 60.0 * PI * wheel_diameter,
+        )
+    }
+
+    /// Returns a copy of this model with no slip or IMU error, for checking a route's ideal
+    /// path.
+    pub const fn ideal(self) -> Self {
+        Self {
+            slip: 0.0,
+            imu_drift: 0.0,
+            imu_noise: 0.0,
+            ..self
+        }
+    }
+}
+
+/// The simulated robot's true state.
+///
+/// Positions are in inches and headings in radians, counterclockwise from +x.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DriveState {
+    pub x: f64,
+    pub y: f64,
+
+    /// Heading, wrapped to `(-π, π]`.
+    pub heading: f64,
+
+    /// Speed of the left and right wheels' surfaces, in inches per second.
+    pub wheel_velocities: [f64; 2],
+
+    /// Distance each powered wheel's surface has turned through, in inches.
+    pub wheel_travel: [f64; 2],
+
+    /// Distance the robot has moved forward, in inches, as an unpowered wheel at its center
+    /// would see it.
+    pub forward_travel: f64,
+
+    /// Angle the robot has turned through since the simulation started, unwrapped.
+    pub rotation: f64,
+
+    /// Rate the robot is turning at, in radians per second.
+    pub angular_velocity: f64,
+}
+
+impl DriveState {
+    pub fn new(x: f64, y: f64, heading: f64) -> Self {
+        Self {
+            x,
+            y,
+            heading: wrap(heading),
+            wheel_velocities: [0.0; 2],
+            wheel_travel: [0.0; 2],
+            forward_travel: 0.0,
+            rotation: 0.0,
+            angular_velocity: 0.0,
+        }
+    }
+
+    /// Advances the state by `dt` seconds with `voltages` applied to the left and right sides.
+    pub fn step(&mut self, model: &DriveModel, voltages: [f64; 2], dt: f64, noise: &mut Noise) {
+        let response = (dt / model.time_constant).min(1.0);
+        let mut ground = [0.0; 2];
+
+        for (side, voltage) in voltages.into_iter().enumerate() {
+            let target = model.free_speed * voltage.clamp(-12.0, 12.0) / 12.0;
+            let velocity = &mut self.wheel_velocities[side];
+
+            *velocity += (target - *velocity) * response;
+            self.wheel_travel[side] += *velocity * dt;
+            ground[side] = *velocity * (1.0 - (model.slip * noise.gaussian()).abs());
+        }
+
+        let linear = (ground[0] + ground[1]) / 2.0;
+        let angular = (ground[1] - ground[0]) / model.track_width;
+
+        // Moving along the average heading over the step keeps arcs from drifting outwards.
+        let midpoint = self.heading + angular * dt / 2.0;
+        self.x += linear * dt * cos(midpoint);
+        self.y += linear * dt * sin(midpoint);
+        self.heading = wrap(self.heading + angular * dt);
+        self.forward_travel += linear * dt;
+        self.rotation += angular * dt;
+        self.angular_velocity = angular;
+    }
+}
+
+/// Wraps an angle in radians to the range `(-π, π]`.
+pub fn wrap(mut angle: f64) -> f64 {
+    while angle > PI {
+        angle -= TAU;
+    }
+    while angle <= -PI {
+        angle += TAU;
+    }
+
+    angle
+}
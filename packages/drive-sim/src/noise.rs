@@ -0,0 +1,34 @@
+/// Small seeded random number generator, so simulated runs are repeatable.
+///
+/// This is xorshift64*, which is plenty for sensor noise and doesn't need any dependencies.
+#[derive(Debug, Clone)]
+pub struct Noise {
+    state: u64,
+}
+
+impl Noise {
+    pub fn new(seed: u64) -> Self {
+        // Xorshift gets stuck at zero, so the seed is mixed with a constant first.
+        Self {
+            state: (seed ^ 0x9E37_79B9_7F4A_7C15) | 1,
+        }
+    }
+
+    fn next(&mut self) -> u64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        self.state.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    /// Returns a uniformly distributed number in `[-1, 1)`.
+    pub fn uniform(&mut self) -> f64 {
+        (self.next() >> 11) as f64 / (1u64 << 52) as f64 - 1.0
+    }
+
+    /// Returns an approximately normally distributed number with a standard deviation of 1.
+    pub fn gaussian(&mut self) -> f64 {
+        // The sum of three uniform samples on [-1, 1) has a variance of 1.
+        self.uniform() + self.uniform() + self.uniform()
+    }
+}
@@ -0,0 +1,165 @@
+use core::{f64::consts::PI, time::Duration};
+
+use drive_sim::{wrap, Drive, DriveModel, STEP};
+
+/// The robots' drivetrain: 11.5" track, 3.25" wheels at 450 RPM.
+const MODEL: DriveModel = DriveModel::from_rpm(11.5, 3.25, 450.0);
+
+fn ideal(x: f64, y: f64, heading: f64) -> Drive {
+    Drive::new(MODEL.ideal(), x, y, heading, 0)
+}
+
+/// Steps `drive` for `duration`, returning its pose after every step.
+fn trajectory(drive: &mut Drive, duration: Duration) -> Vec<(f64, f64, f64)> {
+    let mut poses = Vec::new();
+
+    while drive.time() < duration {
+        drive.step(STEP);
+        let state = drive.state();
+        poses.push((state.x, state.y, state.heading));
+    }
+
+    poses
+}
+
+#[test]
+fn straight_line_follows_heading() {
+    let heading = PI / 3.0;
+    let mut drive = ideal(10.0, -60.0, heading);
+    drive.set_voltages([12.0, 12.0]);
+
+    for (x, y, pose_heading) in trajectory(&mut drive, Duration::from_secs(2)) {
+        let along = (x - 10.0) * heading.cos() + (y + 60.0) * heading.sin();
+        let across = -(x - 10.0) * heading.sin() + (y + 60.0) * heading.cos();
+
+        assert!(along >= 0.0);
+        assert!(across.abs() < 1e-9, "drifted {across} in. sideways");
+        assert!((pose_heading - heading).abs() < 1e-9);
+    }
+
+    let state = drive.state();
+    let travel = ((state.x - 10.0).powi(2) + (state.y + 60.0).powi(2)).sqrt();
+    assert!((travel - state.forward_travel).abs() < 1e-9);
+    assert_eq!(state.wheel_travel[0], state.wheel_travel[1]);
+}
+
+#[test]
+fn speed_follows_first_order_lag() {
+    let model = MODEL.ideal();
+    let mut drive = ideal(0.0, 0.0, 0.0);
+    drive.set_voltages([12.0, 6.0]);
+
+    drive.advance(Duration::from_secs_f64(model.time_constant));
+    let [left, right] = drive.state().wheel_velocities;
+    let expected = 1.0 - (-1.0f64).exp();
+    assert!((left / model.free_speed - expected).abs() < 0.01);
+    assert!((right / (model.free_speed / 2.0) - expected).abs() < 0.01);
+
+    // Speed has settled after ten time constants, and distance is the free speed less the lag.
+    let settle = Duration::from_secs_f64(model.time_constant * 10.0);
+    let mut drive = ideal(0.0, 0.0, 0.0);
+    drive.set_voltages([12.0, 12.0]);
+    drive.advance(settle);
+
+    let state = drive.state();
+    let expected_travel = model.free_speed * (settle.as_secs_f64() - model.time_constant);
+    assert!((state.wheel_velocities[0] - model.free_speed).abs() < 0.01);
+    assert!((state.forward_travel - expected_travel).abs() / expected_travel < 0.01);
+}
+
+#[test]
+fn voltage_is_clamped() {
+    let mut drive = ideal(0.0, 0.0, 0.0);
+    drive.set_voltages([24.0, -24.0]);
+
+    for _ in 0..2000 {
+        drive.step(STEP);
+        let [left, right] = drive.state().wheel_velocities;
+        assert!(left <= MODEL.free_speed && right >= -MODEL.free_speed);
+    }
+
+    let [left, right] = drive.state().wheel_velocities;
+    assert!((left - MODEL.free_speed).abs() < 1e-4);
+    assert!((right + MODEL.free_speed).abs() < 1e-4);
+}
+
+#[test]
+fn turns_in_place() {
+    let mut drive = ideal(-20.0, 30.0, 0.0);
+    drive.set_voltages([-6.0, 6.0]);
+
+    for (x, y, _) in trajectory(&mut drive, Duration::from_secs(3)) {
+        assert!((x + 20.0).abs() < 1e-9 && (y - 30.0).abs() < 1e-9);
+    }
+
+    // Counterclockwise, at the rate the wheel speeds give.
+    let state = drive.state();
+    let expected = (state.wheel_travel[1] - state.wheel_travel[0]) / MODEL.track_width;
+    assert!(state.rotation > 2.0 * PI);
+    assert!((state.rotation - expected).abs() < 1e-9);
+    assert!((state.heading - wrap(expected)).abs() < 1e-9);
+}
+
+#[test]
+fn arc_keeps_constant_radius() {
+    let heading = PI / 2.0;
+    let mut drive = ideal(0.0, 0.0, heading);
+    drive.set_voltages([4.0, 8.0]);
+
+    // Let the wheels reach speed before checking the circle they settle onto.
+    drive.advance(Duration::from_secs(2));
+    let state = *drive.state();
+    let [left, right] = state.wheel_velocities;
+    let radius = MODEL.track_width / 2.0 * (left + right) / (right - left);
+    let center = (
+        state.x - radius * state.heading.sin(),
+        state.y + radius * state.heading.cos(),
+    );
+
+    for (x, y, _) in trajectory(&mut drive, Duration::from_secs(8)) {
+        let distance = ((x - center.0).powi(2) + (y - center.1).powi(2)).sqrt();
+        assert!(
+            (distance - radius).abs() < 0.01,
+            "{distance} in. from the center of a {radius} in. arc"
+        );
+    }
+}
+
+#[test]
+fn slip_only_shortens_travel() {
+    let mut drive = Drive::new(MODEL, 0.0, 0.0, 0.0, 7);
+    drive.set_voltages([10.0, 10.0]);
+    drive.advance(Duration::from_secs(2));
+
+    let state = drive.state();
+    let wheel_travel = (state.wheel_travel[0] + state.wheel_travel[1]) / 2.0;
+    assert!(state.forward_travel < wheel_travel);
+    assert!(state.forward_travel > wheel_travel * (1.0 - 4.0 * MODEL.slip));
+}
+
+#[test]
+fn runs_repeat_with_the_same_seed() {
+    let run = |seed| {
+        let mut drive = Drive::new(MODEL, 0.0, 0.0, 0.0, seed);
+        drive.set_voltages([8.0, 11.0]);
+        trajectory(&mut drive, Duration::from_secs(2))
+    };
+
+    assert_eq!(run(3), run(3));
+    assert_ne!(run(3), run(4));
+}
+
+#[test]
+fn imu_drifts_at_the_model_rate() {
+    let model = DriveModel {
+        imu_drift: 0.01,
+        imu_noise: 0.0,
+        ..MODEL.ideal()
+    };
+    let mut drive = Drive::new(model, 0.0, 0.0, 0.0, 0);
+    drive.advance(Duration::from_secs(10));
+
+    assert_eq!(drive.state().rotation, 0.0);
+    assert!((drive.imu_rotation() - 0.1).abs() < 1e-9);
+    assert!((drive.imu_rate() - 0.01).abs() < 1e-12);
+}
@@ -0,0 +1,203 @@
+//! Summarizes a log captured from the robot's serial output or SD card.
+//!
+//! ```console
+//! cargo v5 terminal > match.log
+//! cargo host -p log-analyze -- match.log --level info --module aubie2::subsystems
+//! ```
+//!
+//! Matching records are printed without their color escapes, followed by a summary of the run:
+//! record counts per level, IMU calibrations, completed routes, rejected rings and any warnings
+//! or errors. Reads from stdin if no file is given, and skips anything that isn't a log record
+//! (such as panics or binary telemetry).
+//!
+//! Options:
+//!
+//! - `--level <level>`: only include records at or above this level.
+//! - `--module <path>`: only include records from this module or its children.
+//! - `--from <MM:SS[:mmm]>` and `--to <MM:SS[:mmm]>`: only include records in this time range.
+//! - `--quiet`: only print the summary.
+
+mod report;
+
+use std::{
+    env,
+    fs::File,
+    io::{self, BufRead, BufReader, BufWriter, Read, Write},
+    process::ExitCode,
+    time::Duration,
+};
+
+use log_line::{parse_timestamp, strip_ansi, Level, LogLine};
+use report::Summary;
+
+#[derive(Debug, Default)]
+struct Options {
+    path: Option<String>,
+    level: Option<Level>,
+    module: Option<String>,
+    from: Option<Duration>,
+    to: Option<Duration>,
+    quiet: bool,
+}
+
+impl Options {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut options = Self::default();
+
+        while let Some(arg) = args.next() {
+            let mut value = |name: &str| {
+                args.next()
+                    .ok_or_else(|| format!("missing value for {name}"))
+            };
+
+            match arg.as_str() {
+                "--level" => options.level = Some(value("--level")?.parse()?),
+                "--module" => options.module = Some(value("--module")?),
+                "--from" => options.from = Some(time_arg(&value("--from")?)?),
+                "--to" => options.to = Some(time_arg(&value("--to")?)?),
+                "-q" | "--quiet" => options.quiet = true,
+                flag if flag.starts_with('-') => return Err(format!("unknown option {flag}")),
+                _ if options.path.is_none() => options.path = Some(arg),
+                _ => return Err(format!("unexpected argument {arg}")),
+            }
+        }
+
+        Ok(options)
+    }
+
+    fn matches(&self, line: &LogLine<'_>) -> bool {
+        self.level.is_none_or(|level| line.level <= level)
+            && self
+                .module
+                .as_ref()
+                .is_none_or(|module| line.is_from(module))
+            && self.from.is_none_or(|from| line.timestamp >= from)
+            && self.to.is_none_or(|to| line.timestamp <= to)
+    }
+}
+
+fn time_arg(value: &str) -> Result<Duration, String> {
+    parse_timestamp(value).ok_or_else(|| format!("invalid time `{value}`, expected MM:SS[:mmm]"))
+}
+
+fn main() -> ExitCode {
+    let options = match Options::parse(env::args().skip(1)) {
+        Ok(options) => options,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let input: Box<dyn Read> = match &options.path {
+        Some(path) => match File::open(path) {
+            Ok(file) => Box::new(file),
+            Err(err) => {
+                eprintln!("error: couldn't open {path}: {err}");
+                return ExitCode::FAILURE;
+            }
+        },
+        None => Box::new(io::stdin().lock()),
+    };
+
+    match analyze(input, BufWriter::new(io::stdout().lock()), &options) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn analyze(input: impl Read, mut output: impl Write, options: &Options) -> io::Result<()> {
+    let mut summary = Summary::default();
+    let mut input = BufReader::new(input);
+    let mut raw = Vec::new();
+
+    // Captures can contain binary telemetry frames, so lines are read as bytes and decoded
+    // lossily rather than failing on invalid UTF-8. Frames end in a zero byte, which never
+    // appears in text, so anything up to the last one on a line is a frame.
+    while input.read_until(b'\n', &mut raw)? != 0 {
+        let start = raw
+            .iter()
+            .rposition(|&byte| byte == 0)
+            .map_or(0, |end| end + 1);
+        let text = strip_ansi(&String::from_utf8_lossy(&raw[start..]));
+        raw.clear();
+
+        let Some(line) = LogLine::parse(&text) else {
+            if !text.trim().is_empty() {
+                summary.skip();
+            }
+            continue;
+        };
+
+        if options.matches(&line) {
+            if !options.quiet {
+                writeln!(output, "{line}")?;
+            }
+            summary.add(&line);
+        }
+    }
+
+    if !options.quiet {
+        writeln!(output)?;
+    }
+    summary.write(&mut output)?;
+    output.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CAPTURE: &str = "\
+00:00:12 \x1B[34m[INFO aubie2::hardware::calibration]\x1B[0m Calibration completed in 2.5s.
+00:20:150 \x1B[34m[INFO aubie2::subsystems::intake]\x1B[0m Rejected Red ring with hue 20.
+00:21:900 \x1B[33m[WARN aubie2::hardware::dual_gyro]\x1B[0m Gyro 1 lost, relying on the remaining sensor.
+00:30:000 \x1B[34m[INFO blue]\x1B[0m Route completed successfully in 14.25s.
+";
+
+    fn run(args: &[&str]) -> String {
+        let options = Options::parse(args.iter().map(ToString::to_string)).unwrap();
+        let mut output = Vec::new();
+        analyze(CAPTURE.as_bytes(), &mut output, &options).unwrap();
+
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn filters_records() {
+        let output = run(&["--module", "aubie2", "--from", "00:15", "--level", "info"]);
+
+        assert!(output.starts_with(
+            "00:20:150 [INFO aubie2::subsystems::intake] Rejected Red ring with hue 20.\n\
+             00:21:900 [WARN aubie2::hardware::dual_gyro] Gyro 1 lost"
+        ));
+        assert!(output.contains("2 records from 00:20:150 to 00:21:900"));
+        assert!(output.contains("  Red: 1 (hue 20.0..20.0, mean 20.0)"));
+    }
+
+    #[test]
+    fn skips_telemetry_frames() {
+        let mut capture = b"\x00\x05\x01\xF4\x01\x02\x00".to_vec();
+        capture.extend_from_slice(CAPTURE.as_bytes());
+
+        let options = Options::parse(["--quiet".to_string()].into_iter()).unwrap();
+        let mut output = Vec::new();
+        analyze(capture.as_slice(), &mut output, &options).unwrap();
+
+        assert!(String::from_utf8(output)
+            .unwrap()
+            .starts_with("== Summary ==\n4 records"));
+    }
+
+    #[test]
+    fn summarizes_run() {
+        let output = run(&["--quiet"]);
+
+        assert!(output.starts_with("== Summary ==\n4 records"));
+        assert!(output.contains("  completed in 2.5s"));
+        assert!(output.contains("  00:30:000 completed in 14.25s"));
+    }
+}
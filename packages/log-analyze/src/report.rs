@@ -0,0 +1,223 @@
+//! Match events extracted from log messages, and the summary built from them.
+
+use std::{
+    collections::BTreeMap,
+    io::{self, Write},
+    time::Duration,
+};
+
+use log_line::{format_timestamp, parse_debug_duration, Level, LogLine};
+
+/// Number of warnings and errors listed in full in the summary.
+const MAX_LISTED_PROBLEMS: usize = 20;
+
+/// Something notable that happened during a run, recognized from a log message.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event {
+    RingRejected { color: String, hue: f64 },
+    RouteCompleted { duration: Duration },
+    CalibrationCompleted { duration: Duration },
+    CalibrationFailed,
+    RecordsDropped { count: usize },
+}
+
+impl Event {
+    pub fn from_message(message: &str) -> Option<Self> {
+        let message = message.strip_suffix('.').unwrap_or(message);
+
+        if let Some(rest) = message.strip_prefix("Rejected ") {
+            let (color, hue) = rest.split_once(" ring with hue ")?;
+
+            return Some(Self::RingRejected {
+                color: color.to_string(),
+                hue: hue.parse().ok()?,
+            });
+        }
+
+        if let Some(duration) = message.strip_prefix("Route completed successfully in ") {
+            return Some(Self::RouteCompleted {
+                duration: parse_debug_duration(duration)?,
+            });
+        }
+
+        if let Some(duration) = message.strip_prefix("Calibration completed in ") {
+            return Some(Self::CalibrationCompleted {
+                duration: parse_debug_duration(duration)?,
+            });
+        }
+
+        if message.starts_with("Calibration fail!") {
+            return Some(Self::CalibrationFailed);
+        }
+
+        if let Some(count) = message.strip_suffix(" records dropped, log queue was full") {
+            return Some(Self::RecordsDropped {
+                count: count.parse().ok()?,
+            });
+        }
+
+        None
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Summary {
+    lines: usize,
+    unparsed: usize,
+    levels: BTreeMap<Level, usize>,
+    span: Option<(Duration, Duration)>,
+    rejected_rings: BTreeMap<String, Vec<f64>>,
+    routes: Vec<(Duration, Duration)>,
+    calibrations: Vec<Duration>,
+    calibration_failures: usize,
+    dropped_records: usize,
+    problems: Vec<String>,
+}
+
+impl Summary {
+    /// Records a line that couldn't be parsed as a log record.
+    pub fn skip(&mut self) {
+        self.unparsed += 1;
+    }
+
+    pub fn add(&mut self, line: &LogLine<'_>) {
+        self.lines += 1;
+        *self.levels.entry(line.level).or_default() += 1;
+
+        self.span = Some(match self.span {
+            Some((start, end)) => (start.min(line.timestamp), end.max(line.timestamp)),
+            None => (line.timestamp, line.timestamp),
+        });
+
+        if line.level <= Level::Warn {
+            self.problems.push(line.to_string());
+        }
+
+        match Event::from_message(line.message) {
+            Some(Event::RingRejected { color, hue }) => {
+                self.rejected_rings.entry(color).or_default().push(hue);
+            }
+            Some(Event::RouteCompleted { duration }) => {
+                self.routes.push((line.timestamp, duration))
+            }
+            Some(Event::CalibrationCompleted { duration }) => self.calibrations.push(duration),
+            Some(Event::CalibrationFailed) => self.calibration_failures += 1,
+            Some(Event::RecordsDropped { count }) => self.dropped_records += count,
+            None => {}
+        }
+    }
+
+    pub fn write(&self, mut out: impl Write) -> io::Result<()> {
+        writeln!(out, "== Summary ==")?;
+        write!(out, "{} records", self.lines)?;
+        if let Some((start, end)) = self.span {
+            write!(
+                out,
+                " from {} to {}",
+                format_timestamp(start),
+                format_timestamp(end)
+            )?;
+        }
+        writeln!(out, " ({} other lines skipped)", self.unparsed)?;
+
+        for level in Level::ALL {
+            writeln!(
+                out,
+                "  {:<5} {}",
+                level,
+                self.levels.get(&level).copied().unwrap_or_default()
+            )?;
+        }
+
+        if self.dropped_records > 0 {
+            writeln!(
+                out,
+                "  {} records were dropped by the logger",
+                self.dropped_records
+            )?;
+        }
+
+        writeln!(out, "\n== Calibration ==")?;
+        if self.calibrations.is_empty() && self.calibration_failures == 0 {
+            writeln!(out, "  none")?;
+        }
+        for duration in &self.calibrations {
+            writeln!(out, "  completed in {duration:?}")?;
+        }
+        if self.calibration_failures > 0 {
+            writeln!(out, "  {} failed", self.calibration_failures)?;
+        }
+
+        writeln!(out, "\n== Routes ==")?;
+        if self.routes.is_empty() {
+            writeln!(out, "  none completed")?;
+        }
+        for (timestamp, duration) in &self.routes {
+            writeln!(
+                out,
+                "  {} completed in {duration:?}",
+                format_timestamp(*timestamp)
+            )?;
+        }
+
+        writeln!(out, "\n== Rejected rings ==")?;
+        if self.rejected_rings.is_empty() {
+            writeln!(out, "  none")?;
+        }
+        for (color, hues) in &self.rejected_rings {
+            let min = hues.iter().copied().fold(f64::INFINITY, f64::min);
+            let max = hues.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+            let mean = hues.iter().sum::<f64>() / hues.len() as f64;
+
+            writeln!(
+                out,
+                "  {color}: {} (hue {min:.1}..{max:.1}, mean {mean:.1})",
+                hues.len()
+            )?;
+        }
+
+        writeln!(out, "\n== Warnings and errors ==")?;
+        if self.problems.is_empty() {
+            writeln!(out, "  none")?;
+        }
+        for problem in self.problems.iter().take(MAX_LISTED_PROBLEMS) {
+            writeln!(out, "  {problem}")?;
+        }
+        if self.problems.len() > MAX_LISTED_PROBLEMS {
+            writeln!(
+                out,
+                "  ... and {} more",
+                self.problems.len() - MAX_LISTED_PROBLEMS
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extracts_events() {
+        assert_eq!(
+            Event::from_message("Rejected Blue ring with hue 220.5."),
+            Some(Event::RingRejected {
+                color: "Blue".to_string(),
+                hue: 220.5
+            })
+        );
+        assert_eq!(
+            Event::from_message("Route completed successfully in 14.25s."),
+            Some(Event::RouteCompleted {
+                duration: Duration::from_millis(14_250)
+            })
+        );
+        assert_eq!(
+            Event::from_message("3 records dropped, log queue was full."),
+            Some(Event::RecordsDropped { count: 3 })
+        );
+        assert_eq!(Event::from_message("Calibrating IMU (attempt 1/3)"), None);
+    }
+}
@@ -0,0 +1,223 @@
+//! Parsing for lines written by `aubie2::logger::SerialLogger`, shared by the host tools that
+//! read serial captures and SD card logs.
+//!
+//! Lines look like `MM:SS:mmm [LEVEL target] message`, with the bracketed part wrapped in ANSI
+//! color escapes on serial. Logs from before the target was added (`[LEVEL] message`) are also
+//! accepted.
+
+use std::{fmt, str::FromStr, time::Duration};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Level {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    pub const ALL: [Self; 5] = [
+        Self::Error,
+        Self::Warn,
+        Self::Info,
+        Self::Debug,
+        Self::Trace,
+    ];
+}
+
+impl FromStr for Level {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "error" => Ok(Self::Error),
+            "warn" => Ok(Self::Warn),
+            "info" => Ok(Self::Info),
+            "debug" => Ok(Self::Debug),
+            "trace" => Ok(Self::Trace),
+            _ => Err(format!("invalid log level `{s}`")),
+        }
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Error => "ERROR",
+            Self::Warn => "WARN",
+            Self::Info => "INFO",
+            Self::Debug => "DEBUG",
+            Self::Trace => "TRACE",
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogLine<'a> {
+    pub timestamp: Duration,
+    pub level: Level,
+    pub target: Option<&'a str>,
+    pub message: &'a str,
+}
+
+impl<'a> LogLine<'a> {
+    /// Parses a line with its color escapes already removed (see [`strip_ansi`]).
+    pub fn parse(line: &'a str) -> Option<Self> {
+        let (timestamp, rest) = line.trim_end().split_once(' ')?;
+        let (tag, message) = rest.strip_prefix('[')?.split_once(']')?;
+
+        let (level, target) = match tag.split_once(' ') {
+            Some((level, target)) => (level, Some(target)),
+            None => (tag, None),
+        };
+
+        Some(Self {
+            timestamp: parse_timestamp(timestamp)?,
+            level: level.parse().ok()?,
+            target,
+            message: message.strip_prefix(' ').unwrap_or(message),
+        })
+    }
+
+    /// Returns `true` if this line came from `module` or one of its children.
+    pub fn is_from(&self, module: &str) -> bool {
+        self.target.is_some_and(|target| {
+            target
+                .strip_prefix(module)
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+        })
+    }
+}
+
+impl fmt::Display for LogLine<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} [{}", format_timestamp(self.timestamp), self.level)?;
+
+        if let Some(target) = self.target {
+            write!(f, " {target}")?;
+        }
+
+        write!(f, "] {}", self.message)
+    }
+}
+
+/// Parses a `MM:SS` or `MM:SS:mmm` timestamp.
+pub fn parse_timestamp(timestamp: &str) -> Option<Duration> {
+    let mut parts = timestamp.split(':');
+
+    let mins: u64 = parts.next()?.parse().ok()?;
+    let secs: u64 = parts.next()?.parse().ok()?;
+    let millis: u64 = match parts.next() {
+        Some(millis) => millis.parse().ok()?,
+        None => 0,
+    };
+
+    if parts.next().is_some() || secs >= 60 || millis >= 1000 {
+        return None;
+    }
+
+    Some(Duration::from_millis((mins * 60 + secs) * 1000 + millis))
+}
+
+pub fn format_timestamp(timestamp: Duration) -> String {
+    format!(
+        "{:02}:{:02}:{:03}",
+        timestamp.as_secs() / 60,
+        timestamp.as_secs() % 60,
+        timestamp.subsec_millis()
+    )
+}
+
+/// Parses a duration printed with `{:?}`, such as `14.25s` or `850ms`.
+pub fn parse_debug_duration(duration: &str) -> Option<Duration> {
+    let split = duration.find(|c: char| !(c.is_ascii_digit() || c == '.'))?;
+    let (value, unit) = duration.split_at(split);
+    let value: f64 = value.parse().ok()?;
+
+    let secs = match unit {
+        "s" => value,
+        "ms" => value / 1e3,
+        "µs" | "us" => value / 1e6,
+        "ns" => value / 1e9,
+        _ => return None,
+    };
+
+    Duration::try_from_secs_f64(secs).ok()
+}
+
+/// Removes ANSI escape sequences (such as colors) from a line.
+pub fn strip_ansi(line: &str) -> String {
+    let mut stripped = String::with_capacity(line.len());
+    let mut chars = line.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\x1B' {
+            stripped.push(c);
+            continue;
+        }
+
+        // CSI sequences end at the first character in the `@`..=`~` range.
+        if chars.next() == Some('[') {
+            for c in chars.by_ref() {
+                if ('@'..='~').contains(&c) {
+                    break;
+                }
+            }
+        }
+    }
+
+    stripped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_colored_line() {
+        let raw = "01:02:45 \x1B[34m[INFO aubie2::subsystems::intake]\x1B[0m Rejected Blue ring with hue 220.5.";
+        let stripped = strip_ansi(raw);
+        let line = LogLine::parse(&stripped).unwrap();
+
+        assert_eq!(line.timestamp, Duration::from_millis(62_045));
+        assert_eq!(line.level, Level::Info);
+        assert_eq!(line.target, Some("aubie2::subsystems::intake"));
+        assert_eq!(line.message, "Rejected Blue ring with hue 220.5.");
+        assert!(line.is_from("aubie2::subsystems"));
+        assert!(!line.is_from("aubie2::sub"));
+    }
+
+    #[test]
+    fn parses_line_without_target() {
+        let stripped = strip_ansi("00:15:250 \x1B[33m[WARN]\x1B[0m Calibration attempt 1 failed");
+        let line = LogLine::parse(&stripped).unwrap();
+
+        assert_eq!(line.level, Level::Warn);
+        assert_eq!(line.target, None);
+        assert_eq!(line.message, "Calibration attempt 1 failed");
+    }
+
+    #[test]
+    fn rejects_other_output() {
+        assert_eq!(LogLine::parse("panicked at src/main.rs:10:5"), None);
+        assert_eq!(LogLine::parse(""), None);
+    }
+
+    #[test]
+    fn parses_debug_durations() {
+        assert_eq!(
+            parse_debug_duration("14.25s"),
+            Some(Duration::from_millis(14_250))
+        );
+        assert_eq!(
+            parse_debug_duration("850ms"),
+            Some(Duration::from_millis(850))
+        );
+        assert_eq!(
+            parse_debug_duration("1.5µs"),
+            Some(Duration::from_nanos(1500))
+        );
+        assert_eq!(parse_debug_duration("fast"), None);
+    }
+}
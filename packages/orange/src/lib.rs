@@ -0,0 +1,389 @@
+#![no_std]
+
+extern crate alloc;
+
+pub mod routes;
+
+use alloc::rc::Rc;
+use core::{cell::RefCell, time::Duration};
+
+use aubie2::{
+    field_map,
+    hardware::{calibrate_imu, calibrate_imu_scale, CustomEncoder, ImuCalibration, ScaledImu},
+    logger::{alerts, console},
+    route::Timeline,
+    selector::RouteSelector,
+    subsystems::{
+        lady_brown::{LadyBrown, LadyBrownTarget},
+        Intake,
+    },
+    telemetry,
+    tracking::SharedTracking,
+};
+use evian::{
+    control::loops::{AngularPid, Pid},
+    prelude::*,
+};
+use log::{error, info, warn};
+use routes::ROUTES;
+use vexide::{prelude::*, time::Instant};
+
+// MARK: Robot
+
+pub struct Robot {
+    controller: Controller,
+    display: Rc<RefCell<Display>>,
+    selector: RouteSelector<Robot>,
+    timeline: Timeline,
+    drivetrain: Drivetrain<Differential, SharedTracking>,
+    intake: Intake,
+    lady_brown: LadyBrown,
+    clamp: AdiDigitalOut,
+    left_arm: AdiDigitalOut,
+    right_arm: AdiDigitalOut,
+    pinchers: AdiDigitalOut,
+}
+
+impl Robot {
+    // Measurements
+    pub const TRACK_WIDTH: f64 = 11.5;
+    pub const WHEEL_DIAMETER: f64 = 2.75;
+    pub const TRACKING_WHEEL_DIAMETER: f64 = 2.0;
+
+    pub const SIDEWAYS_TRACKING_WHEEL_OFFSET: f64 = -2.0;
+
+    /// Rotation correction for the IMU, measured with `calibrate_imu_scale` by holding Y while
+    /// the IMU calibrates at startup.
+    pub const IMU_SCALE: f64 = 1.0;
+
+    // Lady Brown Positions
+    pub const LADY_BROWN_LOWERED: LadyBrownTarget =
+        LadyBrownTarget::Position(Position::from_degrees(190.0));
+    pub const LADY_BROWN_RAISED: LadyBrownTarget =
+        LadyBrownTarget::Position(Position::from_degrees(154.0));
+    pub const LADY_BROWN_UP: LadyBrownTarget =
+        LadyBrownTarget::Position(Position::from_degrees(35.0));
+    pub const LADY_BROWN_SCORED: LadyBrownTarget =
+        LadyBrownTarget::Position(Position::from_degrees(25.0));
+    pub const LADY_BROWN_FLAT: LadyBrownTarget =
+        LadyBrownTarget::Position(Position::from_degrees(-15.0));
+
+    // Control Loops
+    pub const LINEAR_PID: Pid = Pid::new(1.5, 0.1, 0.125, Some(3.0));
+    pub const ANGUALR_PID: AngularPid =
+        AngularPid::new(25.0, 2.0, 1.0, Some(Angle::from_degrees(5.0)));
+    pub const LADY_BROWN_PID: Pid = Pid::new(0.19, 0.01, 0.01, Some(3.0));
+
+    // Tolerances
+    pub const LINEAR_TOLERANCES: Tolerances = Tolerances::new()
+        .error(5.0)
+        .velocity(0.25)
+        .duration(Duration::from_millis(15));
+    pub const ANGULAR_TOLERANCES: Tolerances = Tolerances::new()
+        .error(f64::to_radians(8.0))
+        .velocity(0.05)
+        .duration(Duration::from_millis(15));
+}
+
+// MARK: Competition
+
+impl Compete for Robot {
+    async fn disabled(&mut self) {
+        field_map::hide();
+        self.selector
+            .run(
+                &self.display,
+                &mut self.controller,
+                &self.drivetrain.tracking,
+            )
+            .await;
+    }
+
+    async fn autonomous(&mut self) {
+        field_map::show();
+
+        let Some(route) = self.selector.selected() else {
+            warn!("No route selected, skipping autonomous.");
+            return;
+        };
+        let start = Instant::now();
+
+        info!("Running route `{}`.", route.name());
+        match route.start() {
+            Some(start) => start.apply(&self.drivetrain.tracking),
+            None => warn!("`{}` has no start pose, tracking from here.", route.name()),
+        }
+        field_map::set_route(route);
+        self.timeline
+            .start(&self.drivetrain.tracking, route.period());
+        route.run(self).await;
+
+        // A route that fell back from a failed step still finishes, but didn't succeed.
+        match self.timeline.failure() {
+            None => info!("Route completed successfully in {:?}.", start.elapsed()),
+            Some(failed) => warn!(
+                "Route fell back after {} and finished in {:?}.",
+                failed,
+                start.elapsed()
+            ),
+        }
+        info!(
+            "Position: {}\nHeading: {}° ({}rad)",
+            self.drivetrain.tracking.position(),
+            self.drivetrain.tracking.heading().as_degrees(),
+            self.drivetrain.tracking.heading().as_radians()
+        );
+        self.timeline.log();
+    }
+
+    async fn driver(&mut self) {
+        field_map::show();
+        self.lady_brown.set_target(Self::LADY_BROWN_LOWERED);
+        self.intake.disable_jam_prevention();
+        self.intake.set_reject_color(None);
+        _ = self.intake.lower();
+
+        loop {
+            let state = self.controller.state().unwrap_or_default();
+
+            // Single-stick arcade joystick control
+            _ = self.drivetrain.motors.set_voltages(
+                Voltages::from_arcade(
+                    state.left_stick.y() * Motor::V5_MAX_VOLTAGE,
+                    state.left_stick.x() * Motor::V5_MAX_VOLTAGE,
+                )
+                .normalized(Motor::V5_MAX_VOLTAGE),
+            );
+
+            // Raise/lower ladybrown when B is pressed.
+            if state.button_b.is_now_pressed() {
+                self.lady_brown.set_target(match self.lady_brown.target() {
+                    Self::LADY_BROWN_LOWERED | LadyBrownTarget::Manual(_) => {
+                        Self::LADY_BROWN_RAISED
+                    }
+                    _ => Self::LADY_BROWN_LOWERED,
+                });
+            }
+
+            // Manual ladybrown control using R1/R2.
+            if state.button_l1.is_pressed() {
+                self.lady_brown
+                    .set_target(LadyBrownTarget::Manual(MotorControl::Voltage(
+                        Motor::V5_MAX_VOLTAGE,
+                    )));
+            } else if state.button_l2.is_pressed() {
+                self.lady_brown
+                    .set_target(LadyBrownTarget::Manual(MotorControl::Voltage(
+                        -Motor::V5_MAX_VOLTAGE,
+                    )));
+            } else if let LadyBrownTarget::Manual(_) = self.lady_brown.target() {
+                self.lady_brown
+                    .set_target(LadyBrownTarget::Manual(MotorControl::Brake(
+                        BrakeMode::Hold,
+                    )));
+            }
+
+            // Intake control - R1/R2.
+            if state.button_r1.is_pressed() {
+                self.intake.set_voltage(Motor::V5_MAX_VOLTAGE);
+            } else if state.button_r2.is_pressed() {
+                self.intake.set_voltage(-Motor::V5_MAX_VOLTAGE);
+            } else {
+                self.intake.set_voltage(0.0);
+            }
+
+            if state.button_x.is_now_pressed() {
+                _ = self.right_arm.toggle();
+            }
+            if state.button_up.is_now_pressed() {
+                _ = self.left_arm.toggle();
+            }
+            if state.button_right.is_now_pressed() {
+                _ = self.pinchers.toggle();
+            }
+
+            if state.button_y.is_now_pressed() {
+                self.lady_brown.set_target(Self::LADY_BROWN_SCORED);
+            }
+
+            // A to toggle mogo mech.
+            if state.button_a.is_now_pressed() {
+                _ = self.clamp.toggle();
+            }
+
+            alerts::update(&mut self.controller);
+
+            sleep(Motor::UPDATE_INTERVAL).await;
+        }
+    }
+}
+
+// MARK: Setup
+
+/// Full turns spun when measuring the IMU's scale.
+const IMU_SCALE_TURNS: u32 = 5;
+
+impl Robot {
+    /// Sets up the robot's devices and subsystems, calibrating the IMU before returning.
+    pub async fn new(peripherals: Peripherals) -> Self {
+        let enc =
+            CustomEncoder::<8192>::new(peripherals.adi_g, peripherals.adi_h, Direction::Forward);
+        let mut display = peripherals.display;
+        let mut imu = InertialSensor::new(peripherals.port_5);
+        let mut controller = peripherals.primary_controller;
+
+        // Left/right motors shared between the drivetrain, odometry and the route timeline.
+        let left_motors = shared_motors![
+            Motor::new(peripherals.port_11, Gearset::Blue, Direction::Forward),
+            Motor::new(peripherals.port_12, Gearset::Blue, Direction::Reverse),
+            Motor::new(peripherals.port_13, Gearset::Blue, Direction::Forward),
+            Motor::new(peripherals.port_14, Gearset::Blue, Direction::Reverse),
+        ];
+        let right_motors = shared_motors![
+            Motor::new(peripherals.port_17, Gearset::Blue, Direction::Forward),
+            Motor::new(peripherals.port_18, Gearset::Blue, Direction::Reverse),
+            Motor::new(peripherals.port_19, Gearset::Blue, Direction::Forward),
+            Motor::new(peripherals.port_20, Gearset::Blue, Direction::Reverse),
+        ];
+
+        // Fall back to wheel-based heading rather than trusting a bad calibration.
+        let imu = match calibrate_imu(
+            &mut controller,
+            &mut display,
+            &mut imu,
+            ImuCalibration::DEFAULT,
+        )
+        .await
+        {
+            Ok(()) => {
+                // Holding Y as calibration finishes measures the IMU's scale and uses it for this
+                // run. The measured factor is logged so it can be copied into `IMU_SCALE`.
+                let mut scale = Robot::IMU_SCALE;
+                if controller
+                    .state()
+                    .is_ok_and(|state| state.button_y.is_pressed())
+                {
+                    let mut motors =
+                        Differential::from_shared(left_motors.clone(), right_motors.clone());
+                    if let Ok(measured) =
+                        calibrate_imu_scale(&mut controller, &mut motors, &imu, IMU_SCALE_TURNS)
+                            .await
+                    {
+                        scale = measured;
+                    }
+                }
+
+                Some(ScaledImu::new(imu, scale))
+            }
+            Err(err) => {
+                error!("IMU disabled: {}", err);
+                None
+            }
+        };
+
+        // Calibration is done with the display, so share it between the log console, the route
+        // selector and the field map.
+        let display = Rc::new(RefCell::new(display));
+        console::enable(display.clone());
+        alerts::enable(true);
+
+        let robot = Robot {
+            // Controller
+            controller,
+
+            // Route Selection
+            display,
+            selector: RouteSelector::new(ROUTES),
+            // Checkpoint tolerances match the motion tolerances, since a motion can settle that far
+            // from its target without anything having gone wrong.
+            timeline: Timeline::new()
+                .with_checkpoint_tolerance(5.0, Angle::from_degrees(8.0))
+                .with_drift_alerts(true)
+                .with_motors(Differential::from_shared(
+                    left_motors.clone(),
+                    right_motors.clone(),
+                )),
+
+            // Drivetrain Model & Localization
+            drivetrain: {
+                let tracking = SharedTracking::new(WheeledTracking::new(
+                    Vec2::new(0.0, 0.0),
+                    90.0.deg(),
+                    [
+                        TrackingWheel::new(left_motors.clone(), 3.25, -5.75, Some(36.0 / 48.0)),
+                        TrackingWheel::new(right_motors.clone(), 3.25, 5.75, Some(36.0 / 48.0)),
+                    ],
+                    [TrackingWheel::new(
+                        enc,
+                        Robot::TRACKING_WHEEL_DIAMETER,
+                        Robot::SIDEWAYS_TRACKING_WHEEL_OFFSET,
+                        None,
+                    )],
+                    imu,
+                ));
+                telemetry::start_drivetrain_sampler(
+                    tracking.clone(),
+                    left_motors.clone(),
+                    right_motors.clone(),
+                );
+
+                // Drivetrain Model
+                Drivetrain::new(
+                    Differential::from_shared(left_motors, right_motors),
+                    tracking,
+                )
+            },
+
+            // Intake
+            intake: Intake::new(
+                [Motor::new(
+                    peripherals.port_15,
+                    Gearset::Blue,
+                    Direction::Forward,
+                )],
+                [
+                    Motor::new(peripherals.port_1, Gearset::Blue, Direction::Forward),
+                    Motor::new(peripherals.port_7, Gearset::Blue, Direction::Reverse),
+                ],
+                OpticalSensor::new(peripherals.port_21),
+                AdiDigitalOut::new(peripherals.adi_d),
+            ),
+
+            // Lady Brown Arm
+            lady_brown: LadyBrown::new(
+                [Motor::new(
+                    peripherals.port_2,
+                    Gearset::Green,
+                    Direction::Reverse,
+                )],
+                RotationSensor::new(peripherals.port_8, Direction::Forward),
+                Pid::new(0.19, 0.0, 0.01, None),
+            ),
+
+            // Mogo
+            clamp: AdiDigitalOut::new(peripherals.adi_f),
+
+            // Goal Rush Arms
+            left_arm: AdiDigitalOut::new(peripherals.adi_e),
+            right_arm: AdiDigitalOut::new(peripherals.adi_a),
+            pinchers: AdiDigitalOut::new(peripherals.adi_b),
+        };
+
+        field_map::enable(robot.display.clone(), robot.drivetrain.tracking.clone());
+
+        robot
+    }
+
+    /// Selects the route autonomous runs by name, returning `false` if there isn't one.
+    pub fn select_route(&mut self, name: &str) -> bool {
+        self.selector.select_by_name(name)
+    }
+
+    pub const fn timeline(&self) -> &Timeline {
+        &self.timeline
+    }
+
+    pub const fn tracking(&self) -> &SharedTracking {
+        &self.drivetrain.tracking
+    }
+}
@@ -1,5 +1,43 @@
-pub mod blue_safe;
-pub mod red_safe;
-pub mod red_rush;
-pub mod blue_rush;
-pub mod skills;
\ No newline at end of file
+use aubie2::{route::Route, routes};
+
+use crate::Robot;
+
+pub mod rush;
+pub mod safe;
+pub mod script;
+pub mod skills;
+
+/// Routes listed in the selector, in the order they're shown.
+pub const ROUTES: &[&dyn Route<Robot>] = routes![Robot;
+    "Red Safe" {
+        alliance: Red,
+        description: "Alliance stake, goal, three stacks",
+        start: (10.0, -63.0, 214.0),
+        waypoints: Robot::safe_waypoints,
+        run: Robot::safe,
+        mirrored: "Blue Safe",
+    },
+    "Red Rush" {
+        alliance: Red,
+        description: "Goal rush, then goal and stacks",
+        start: (23.0, -61.0, 78.0),
+        waypoints: Robot::rush_waypoints,
+        run: Robot::rush,
+        mirrored: "Blue Rush",
+    },
+    "Skills" {
+        alliance: Red,
+        description: "Programming skills",
+        start: (0.0, -62.0, 270.0),
+        period: 60,
+        run: Robot::skills,
+    },
+    "Red Script" {
+        alliance: Red,
+        description: "Runs route.txt from the SD card",
+        start_from: aubie2::route::script::script_start::<Robot>,
+        check: aubie2::route::script::check_script::<Robot>,
+        run: aubie2::route::script::run_script::<Robot>,
+        mirrored: "Blue Script",
+    },
+];
@@ -0,0 +1,380 @@
+use alloc::vec::Vec;
+use core::time::Duration;
+
+use aubie2::route::Mirror;
+use evian::{
+    math::{IntoAngle, Vec2},
+    motion::{Basic, Seeking},
+};
+
+use crate::Robot;
+
+/// Where the robot backs up to with the rushed goal, before turning to clamp it.
+const GOAL_RETURN: (f64, f64) = (23.0, -61.0);
+
+const FIRST_STACK: (f64, f64) = (44.0, -59.0);
+
+/// Points, distances, gains and steps tuned separately for each alliance, in red's coordinates
+/// (before mirroring).
+///
+/// The blue values come from the hand-tuned copy of this route blue used to run, since the two
+/// sides of a real field don't match exactly. That copy also lowered the intake at a different
+/// point and left the lady brown alone in the corner, so those steps are switched here too.
+struct Tuning {
+    rush_point: (f64, f64),
+    rush_kp: f64,
+    goal_heading: f64,
+    second_stack: (f64, f64),
+    line_approach: f64,
+    line_heading: f64,
+    line_stack: (f64, f64),
+    back_from_line: f64,
+    back_from_line_heading: f64,
+    corner: (f64, f64),
+    corner_again: f64,
+    drop_timeout: Duration,
+
+    /// Lower the intake after backing away from the line stack, rather than before approaching
+    /// it.
+    lower_intake_after_line_stack: bool,
+
+    /// Lay the lady brown flat for the corner, then lower the intake and the lady brown once
+    /// it's cleared out.
+    lady_brown_in_corner: bool,
+}
+
+const RED: Tuning = Tuning {
+    rush_point: (33.0, -26.0),
+    rush_kp: 2.5,
+    goal_heading: 0.0,
+    second_stack: (42.0, -43.0),
+    line_approach: 21.0,
+    line_heading: 90.0,
+    line_stack: (54.0, -23.0),
+    back_from_line: -35.0,
+    back_from_line_heading: 45.0,
+    corner: (56.0, -72.0),
+    corner_again: 12.0,
+    drop_timeout: Duration::from_millis(800),
+    lower_intake_after_line_stack: false,
+    lady_brown_in_corner: true,
+};
+
+const BLUE: Tuning = Tuning {
+    rush_point: (34.0, -25.5),
+    rush_kp: 2.0,
+    goal_heading: 358.5,
+    second_stack: (43.0, -43.0),
+    line_approach: 26.5,
+    line_heading: 84.0,
+    line_stack: (59.0, -25.0),
+    back_from_line: -38.0,
+    back_from_line_heading: 44.0,
+    corner: (59.0, -76.0),
+    corner_again: 13.0,
+    drop_timeout: Duration::from_secs(5),
+    lower_intake_after_line_stack: true,
+    lady_brown_in_corner: false,
+};
+
+impl Robot {
+    /// Points [`Robot::rush`] drives to, for the field map.
+    pub fn rush_waypoints(mirror: Mirror) -> Vec<Vec2<f64>> {
+        let tuning = mirror.side(&RED, &BLUE);
+
+        [
+            tuning.rush_point,
+            GOAL_RETURN,
+            FIRST_STACK,
+            tuning.second_stack,
+            tuning.line_stack,
+            tuning.corner,
+        ]
+        .into_iter()
+        .map(|point| mirror.point(point))
+        .collect()
+    }
+
+    pub async fn rush(&mut self, mirror: Mirror) {
+        self.intake.enable_jam_prevention();
+
+        let tuning = mirror.side(&RED, &BLUE);
+        let dt = &mut self.drivetrain;
+        let tl = &mut self.timeline;
+        // Motions are built without timeouts, since the timeline stops them.
+        let timeout = Some(Duration::from_secs(5));
+        let mut basic = Basic {
+            linear_controller: Robot::LINEAR_PID,
+            angular_controller: Robot::ANGUALR_PID,
+            linear_tolerances: Robot::LINEAR_TOLERANCES,
+            angular_tolerances: Robot::ANGULAR_TOLERANCES,
+            timeout: None,
+        };
+        let mut seeking = Seeking {
+            linear_controller: Robot::LINEAR_PID,
+            angular_controller: Robot::ANGUALR_PID,
+            tolerances: Robot::LINEAR_TOLERANCES,
+            timeout: None,
+        };
+
+        // Goal rush
+        tl.action("Arm down", || {
+            _ = mirror
+                .side(&mut self.right_arm, &mut self.left_arm)
+                .set_high();
+        });
+        tl.motion(
+            "Rush",
+            timeout,
+            seeking
+                .move_to_point(dt, mirror.point(tuning.rush_point))
+                .with_linear_kp(tuning.rush_kp)
+                .without_tolerance_duration(),
+        )
+        .await;
+        tl.checkpoint("Rush", mirror.point(tuning.rush_point), None);
+        tl.action("Pinch", || _ = self.pinchers.set_high());
+
+        tl.motion(
+            "Drag goal",
+            Some(Duration::from_secs_f64(1.5)),
+            basic.drive_distance_at_heading(dt, -17.0, mirror.heading(78.0.deg())),
+        )
+        .await;
+
+        tl.action("Unpinch", || _ = self.pinchers.set_low());
+        tl.motion("Clear goal", timeout, basic.drive_distance(dt, 4.0))
+            .await;
+
+        tl.motion(
+            "Goal return",
+            timeout,
+            seeking
+                .move_to_point(dt, mirror.point(GOAL_RETURN))
+                .reverse(),
+        )
+        .await;
+        tl.checkpoint("Goal return", mirror.point(GOAL_RETURN), None);
+
+        tl.action("Arm up", || {
+            _ = mirror
+                .side(&mut self.right_arm, &mut self.left_arm)
+                .set_low();
+        });
+
+        tl.motion(
+            "Face goal",
+            timeout,
+            basic.turn_to_heading(dt, mirror.heading(0.0.deg())),
+        )
+        .await;
+
+        tl.motion(
+            "Drive into goal",
+            timeout,
+            basic
+                .drive_distance_at_heading(dt, -26.0, mirror.heading(tuning.goal_heading.deg()))
+                .with_linear_output_limit(4.0),
+        )
+        .await;
+        tl.action("Clamp", || _ = self.clamp.set_high());
+
+        tl.wait("Clamp", Duration::from_millis(500)).await;
+
+        // First stack
+        tl.action("Intake on", || self.intake.set_voltage(12.0));
+        tl.motion(
+            "First stack",
+            timeout,
+            seeking.move_to_point(dt, mirror.point(FIRST_STACK)),
+        )
+        .await;
+        tl.checkpoint("First stack", mirror.point(FIRST_STACK), None);
+        tl.wait("First stack", Duration::from_millis(350)).await;
+
+        // Second stack
+        tl.action("Raise intake", || _ = self.intake.raise());
+
+        tl.motion(
+            "Face second stack",
+            timeout,
+            basic.turn_to_heading(dt, mirror.heading(90.0.deg())),
+        )
+        .await;
+
+        tl.motion(
+            "Second stack",
+            timeout,
+            seeking.move_to_point(dt, mirror.point(tuning.second_stack)),
+        )
+        .await;
+        tl.checkpoint("Second stack", mirror.point(tuning.second_stack), None);
+        tl.wait("Second stack", Duration::from_millis(500)).await;
+
+        // Stack at line
+        tl.motion(
+            "Back from stack",
+            timeout,
+            basic.drive_distance_at_heading(dt, -9.0, mirror.heading(45.0.deg())),
+        )
+        .await;
+        if !tuning.lower_intake_after_line_stack {
+            tl.action("Lower intake", || _ = self.intake.lower());
+        }
+        tl.motion(
+            "Line approach",
+            timeout,
+            basic.drive_distance_at_heading(dt, tuning.line_approach, mirror.heading(45.0.deg())),
+        )
+        .await;
+
+        tl.motion(
+            "Face line stack",
+            timeout,
+            basic.turn_to_heading(dt, mirror.heading(tuning.line_heading.deg())),
+        )
+        .await;
+        tl.motion(
+            "Line stack",
+            timeout,
+            seeking.move_to_point(dt, mirror.point(tuning.line_stack)),
+        )
+        .await;
+        tl.checkpoint("Line stack", mirror.point(tuning.line_stack), None);
+
+        tl.wait("Line stack", Duration::from_millis(1000)).await;
+
+        // Final
+        tl.motion(
+            "Back from line",
+            timeout,
+            basic.drive_distance_at_heading(
+                dt,
+                tuning.back_from_line,
+                mirror.heading(tuning.back_from_line_heading.deg()),
+            ),
+        )
+        .await;
+        if tuning.lower_intake_after_line_stack {
+            tl.action("Lower intake", || _ = self.intake.lower());
+        }
+        tl.motion(
+            "Face corner",
+            timeout,
+            basic.turn_to_heading(dt, mirror.heading(315.0.deg())),
+        )
+        .await;
+
+        if tuning.lady_brown_in_corner {
+            tl.action("Lady brown flat", || {
+                self.lady_brown.set_target(Self::LADY_BROWN_FLAT);
+            });
+        }
+        tl.motion(
+            "Corner",
+            Some(Duration::from_secs_f64(2.5)),
+            seeking
+                .move_to_point(dt, mirror.point(tuning.corner))
+                .with_linear_output_limit(4.0),
+        )
+        .await;
+        tl.checkpoint("Corner", mirror.point(tuning.corner), None);
+        tl.action("Intake on", || self.intake.set_voltage(12.0));
+        tl.wait("Corner", Duration::from_millis(800)).await;
+
+        tl.motion("Back from corner", timeout, basic.drive_distance(dt, -14.0))
+            .await;
+        tl.action("Raise intake", || _ = self.intake.raise());
+        tl.motion(
+            "Corner again",
+            timeout,
+            basic
+                .drive_distance(dt, tuning.corner_again)
+                .with_linear_output_limit(4.0),
+        )
+        .await;
+        if tuning.lady_brown_in_corner {
+            tl.action("Lower intake", || _ = self.intake.lower());
+            tl.wait("Corner again", Duration::from_millis(600)).await;
+            tl.action("Lower lady brown", || {
+                self.lady_brown.set_target(Self::LADY_BROWN_LOWERED);
+            });
+        }
+
+        // Clear corner
+        tl.action("Outtake", || {
+            _ = self.intake.lower();
+            self.intake.set_voltage(-1.0);
+        });
+        tl.motion("Back from corner", timeout, basic.drive_distance(dt, -8.0))
+            .await;
+        tl.motion(
+            "Face wall",
+            timeout,
+            basic.turn_to_heading(dt, mirror.heading(270.0.deg())),
+        )
+        .await;
+        tl.action("Arm down", || {
+            _ = mirror
+                .side(&mut self.left_arm, &mut self.right_arm)
+                .set_high();
+        });
+        tl.wait("Arm down", Duration::from_millis(800)).await;
+        tl.motion(
+            "Sweep corner",
+            timeout,
+            basic
+                .drive_distance_at_heading(dt, 5.0, mirror.heading(35.0.deg()))
+                .without_tolerance_duration(),
+        )
+        .await;
+
+        // Drop goal
+        tl.action("Outtake", || self.intake.set_voltage(-1.5));
+        tl.motion(
+            "Face drop",
+            timeout,
+            basic.turn_to_heading(dt, mirror.heading(135.0.deg())),
+        )
+        .await;
+        tl.motion(
+            "Back into drop",
+            Some(tuning.drop_timeout),
+            basic.drive_distance_at_heading(dt, -16.0, mirror.heading(135.0.deg())),
+        )
+        .await;
+        tl.action("Drop goal", || {
+            self.intake.set_voltage(0.0);
+            _ = self.clamp.set_low();
+            _ = mirror
+                .side(&mut self.left_arm, &mut self.right_arm)
+                .set_low();
+        });
+        tl.motion(
+            "Push goal",
+            timeout,
+            basic
+                .drive_distance_at_heading(dt, -4.0, mirror.heading(135.0.deg()))
+                .with_linear_output_limit(4.0),
+        )
+        .await;
+
+        // Align to goal;
+        tl.motion(
+            "Align to goal",
+            timeout,
+            basic.drive_distance_at_heading(dt, 32.0, mirror.heading(90.0.deg())),
+        )
+        .await;
+        tl.motion(
+            "Face goal",
+            timeout,
+            basic.turn_to_heading(dt, mirror.heading(270.0.deg())),
+        )
+        .await;
+
+        // // Touch
+        // basic.drive_distance_at_heading(dt, 52.0, 135.0.deg()).await;
+        // self.lady_brown.set_target(Self::LADY_BROWN_FLAT);
+    }
+}
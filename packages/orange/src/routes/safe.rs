@@ -0,0 +1,464 @@
+use alloc::vec::Vec;
+use core::time::Duration;
+
+use aubie2::route::{Mirror, Outcome, StepFailed, Timeline};
+use evian::{
+    math::{IntoAngle, Vec2},
+    motion::{Basic, Seeking},
+};
+use log::warn;
+
+use crate::Robot;
+
+/// Where the route's normal ending touches the ladder. This is an estimate, dead-reckoned from
+/// the ending's last few moves, and hasn't been measured on a field.
+const LADDER_TOUCH: (f64, f64) = (37.0, -31.0);
+
+/// Time the corner and everything after it are expected to need. Estimated, not timed.
+const CORNER_TIME: Duration = Duration::from_secs(9);
+
+/// Time clearing the corner and touching the ladder are expected to need. Also estimated.
+const CLEAR_CORNER_TIME: Duration = Duration::from_secs(5);
+
+/// Whether running short of [`CORNER_TIME`] or [`CLEAR_CORNER_TIME`] skips to the ladder. Until
+/// they're set from the step times a field run's timeline logs, running short only warns, so a
+/// wrong estimate can't cut the route short.
+const ENFORCE_TIME_BUDGETS: bool = false;
+
+/// The route's time checks, in the order it makes them.
+const TIME_BUDGETS: &[(&str, Duration)] =
+    &[("Corner", CORNER_TIME), ("Clear corner", CLEAR_CORNER_TIME)];
+
+/// Points, distances, waits and steps tuned separately for each alliance, in red's coordinates
+/// (before mirroring).
+///
+/// The blue values come from the hand-tuned copy of this route blue used to run, since the two
+/// sides of a real field don't match exactly. That copy also lowered the intake and released the
+/// goal at different points, so those steps are switched here too.
+struct Tuning {
+    raise_wait: Duration,
+    stake_wait: Duration,
+    goal: (f64, f64),
+    first_stack: (f64, f64),
+    second_stack: (f64, f64),
+    third_stack_distance: f64,
+    line_stack: (f64, f64),
+    back_from_line: f64,
+    corner: (f64, f64),
+    touch_distance: f64,
+    touch_heading: f64,
+
+    /// Lower the intake once the line stack is picked up and give it time to drop, rather than
+    /// on the way to the stack.
+    lower_intake_after_line_stack: bool,
+
+    /// Release the goal before backing it into the drop zone, rather than after, and skip
+    /// pushing it the rest of the way in.
+    release_goal_early: bool,
+}
+
+const RED: Tuning = Tuning {
+    raise_wait: Duration::from_millis(350),
+    stake_wait: Duration::from_millis(950),
+    goal: (26.0, -53.0),
+    first_stack: (41.0, -53.0),
+    second_stack: (43.0, -35.0),
+    third_stack_distance: 17.0,
+    line_stack: (56.0, -21.0),
+    back_from_line: -39.0,
+    corner: (56.0, -72.5),
+    touch_distance: 52.0,
+    touch_heading: 122.0,
+    lower_intake_after_line_stack: false,
+    release_goal_early: false,
+};
+
+const BLUE: Tuning = Tuning {
+    raise_wait: Duration::from_millis(250),
+    stake_wait: Duration::from_millis(1000),
+    goal: (25.5, -52.0),
+    first_stack: (41.0, -53.5),
+    second_stack: (43.0, -36.0),
+    third_stack_distance: 15.0,
+    line_stack: (55.0, -21.0),
+    back_from_line: -40.0,
+    corner: (56.0, -75.0),
+    touch_distance: 50.0,
+    touch_heading: 135.0,
+    lower_intake_after_line_stack: true,
+    release_goal_early: true,
+};
+
+// practice: good
+impl Robot {
+    /// Points [`Robot::safe`] drives to, for the field map.
+    pub fn safe_waypoints(mirror: Mirror) -> Vec<Vec2<f64>> {
+        let tuning = mirror.side(&RED, &BLUE);
+
+        [
+            tuning.goal,
+            tuning.first_stack,
+            tuning.second_stack,
+            tuning.line_stack,
+            tuning.corner,
+        ]
+        .into_iter()
+        .map(|point| mirror.point(point))
+        .collect()
+    }
+
+    pub async fn safe(&mut self, mirror: Mirror) {
+        match self.safe_steps(mirror).await {
+            Ok(()) => {}
+            Err(failed) if failed.outcome == Outcome::OutOfTime => {
+                warn!("{}, going straight to the ladder.", failed);
+                self.safe_touch(mirror).await;
+            }
+            // Everything after the clamp depends on holding the goal, so if it can't be clamped
+            // there's nothing safe left to do but stop and stay out of the way.
+            Err(failed) => {
+                warn!("{}, parking.", failed);
+                self.intake.set_voltage(0.0);
+                self.lady_brown.set_target(Self::LADY_BROWN_LOWERED);
+            }
+        }
+    }
+
+    /// Ending used when the route runs short on time: drive to the ladder from wherever the
+    /// robot is and touch it.
+    async fn safe_touch(&mut self, mirror: Mirror) {
+        let dt = &mut self.drivetrain;
+        let tl = &mut self.timeline;
+        let timeout = Some(tl.remaining());
+        let mut seeking = Seeking {
+            linear_controller: Robot::LINEAR_PID,
+            angular_controller: Robot::ANGUALR_PID,
+            tolerances: Robot::LINEAR_TOLERANCES,
+            timeout: None,
+        };
+
+        tl.action("Stop intake", || self.intake.set_voltage(0.0));
+        tl.motion(
+            "Ladder",
+            timeout,
+            seeking.move_to_point(dt, mirror.point(LADDER_TOUCH)),
+        )
+        .await;
+        tl.action("Lady brown flat", || {
+            self.lady_brown.set_target(Self::LADY_BROWN_FLAT);
+        });
+    }
+
+    async fn safe_steps(&mut self, mirror: Mirror) -> Result<(), StepFailed> {
+        self.intake.enable_jam_prevention();
+
+        let tuning = mirror.side(&RED, &BLUE);
+        let dt = &mut self.drivetrain;
+        let tl = &mut self.timeline;
+        tl.plan(TIME_BUDGETS);
+        // Motions are built without timeouts, since the timeline stops them.
+        let timeout = Some(Duration::from_secs(5));
+        let mut basic = Basic {
+            linear_controller: Robot::LINEAR_PID,
+            angular_controller: Robot::ANGUALR_PID,
+            linear_tolerances: Robot::LINEAR_TOLERANCES,
+            angular_tolerances: Robot::ANGULAR_TOLERANCES,
+            timeout: None,
+        };
+        let mut seeking = Seeking {
+            linear_controller: Robot::LINEAR_PID,
+            angular_controller: Robot::ANGUALR_PID,
+            tolerances: Robot::LINEAR_TOLERANCES,
+            timeout: None,
+        };
+
+        // Alliance stake
+        tl.action("Raise lady brown", || {
+            self.intake.disable_jam_prevention();
+            self.lady_brown.set_target(Self::LADY_BROWN_RAISED);
+        });
+        tl.wait("Lady brown raise", tuning.raise_wait).await;
+        tl.action("Score alliance stake", || self.intake.set_top_voltage(12.0));
+        tl.wait("Alliance stake", tuning.stake_wait).await;
+
+        tl.action("Back off ring", || self.intake.set_top_voltage(-3.0));
+        tl.wait("Back off ring", Duration::from_millis(250)).await;
+
+        tl.action("Lady brown flat", || {
+            self.lady_brown.set_target(Self::LADY_BROWN_FLAT);
+            self.intake.set_top_voltage(0.0);
+        });
+        tl.wait("Lady brown flat", Duration::from_millis(1000))
+            .await;
+
+        // Goal
+        tl.motion(
+            "Back to goal",
+            timeout,
+            seeking
+                .move_to_point(dt, mirror.point(tuning.goal))
+                .reverse(),
+        )
+        .await;
+        tl.checkpoint("Goal approach", mirror.point(tuning.goal), None);
+        tl.action("Lower lady brown", || {
+            self.lady_brown.set_target(Self::LADY_BROWN_LOWERED);
+            self.intake.enable_jam_prevention();
+        });
+
+        tl.motion(
+            "Face goal",
+            timeout,
+            basic.turn_to_heading(dt, mirror.heading(0.0.deg())),
+        )
+        .await;
+        tl.checkpoint(
+            "Face goal",
+            mirror.point(tuning.goal),
+            Some(mirror.heading(0.0.deg())),
+        );
+        let into_goal = tl
+            .motion(
+                "Drive into goal",
+                timeout,
+                basic
+                    .drive_distance_at_heading(dt, -26.0, mirror.heading(0.0.deg()))
+                    .with_linear_output_limit(4.0),
+            )
+            .await;
+        if into_goal.timed_out() {
+            // Probably caught on the goal's edge, so back off and square up before trying again.
+            tl.motion(
+                "Back off goal",
+                timeout,
+                basic.drive_distance_at_heading(dt, 6.0, mirror.heading(0.0.deg())),
+            )
+            .await;
+            tl.critical(
+                "Retry goal",
+                timeout,
+                basic
+                    .drive_distance_at_heading(dt, -10.0, mirror.heading(0.0.deg()))
+                    .with_linear_output_limit(4.0),
+            )
+            .await?;
+        }
+        tl.action("Clamp", || _ = self.clamp.set_high());
+
+        tl.wait("Clamp", Duration::from_millis(500)).await;
+
+        // First stack
+        tl.action("Intake on", || self.intake.set_voltage(12.0));
+        tl.motion(
+            "First stack",
+            timeout,
+            seeking.move_to_point(dt, mirror.point(tuning.first_stack)),
+        )
+        .await;
+        tl.checkpoint("First stack", mirror.point(tuning.first_stack), None);
+        tl.wait("First stack", Duration::from_millis(350)).await;
+
+        // Second stack
+        tl.action("Raise intake", || _ = self.intake.raise());
+
+        tl.motion(
+            "Face second stack",
+            timeout,
+            basic.turn_to_heading(dt, mirror.heading(70.0.deg())),
+        )
+        .await;
+        tl.motion(
+            "Second stack",
+            timeout,
+            seeking.move_to_point(dt, mirror.point(tuning.second_stack)),
+        )
+        .await;
+        tl.checkpoint("Second stack", mirror.point(tuning.second_stack), None);
+        tl.wait("Second stack", Duration::from_millis(500)).await;
+
+        // Clear bottom of stack.
+        tl.motion("Clear stack", timeout, basic.drive_distance(dt, -5.0))
+            .await;
+
+        tl.motion(
+            "Face third stack",
+            timeout,
+            basic
+                .turn_to_heading(dt, mirror.heading(35.0.deg()))
+                .without_tolerance_duration(),
+        )
+        .await;
+        tl.motion(
+            "Third stack",
+            timeout,
+            basic.drive_distance_at_heading(
+                dt,
+                tuning.third_stack_distance,
+                mirror.heading(35.0.deg()),
+            ),
+        )
+        .await;
+
+        tl.motion(
+            "Face line stack",
+            timeout,
+            basic
+                .turn_to_heading(dt, mirror.heading(87.0.deg()))
+                .without_tolerance_duration(),
+        )
+        .await;
+
+        // Stack at line
+        if !tuning.lower_intake_after_line_stack {
+            tl.action("Lower intake", || _ = self.intake.lower());
+        }
+        tl.motion(
+            "Line stack",
+            timeout,
+            seeking.move_to_point(dt, mirror.point(tuning.line_stack)),
+        )
+        .await;
+        tl.checkpoint("Line stack", mirror.point(tuning.line_stack), None);
+        tl.wait("Line stack", Duration::from_millis(1000)).await;
+        if tuning.lower_intake_after_line_stack {
+            tl.action("Lower intake", || _ = self.intake.lower());
+            tl.wait("Lower intake", Duration::from_millis(250)).await;
+        }
+
+        // Final
+        check_time(tl, "Corner", CORNER_TIME)?;
+        tl.motion(
+            "Back from line",
+            timeout,
+            basic.drive_distance_at_heading(dt, tuning.back_from_line, mirror.heading(45.0.deg())),
+        )
+        .await;
+        // seeking
+        //     .move_to_point(dt, (-22.0, -49.0))
+        //     .reverse()
+        //     .await;
+        tl.motion(
+            "Face corner",
+            timeout,
+            basic.turn_to_heading(dt, mirror.heading(315.0.deg())),
+        )
+        .await;
+        tl.action("Lady brown flat", || {
+            self.lady_brown.set_target(Self::LADY_BROWN_FLAT);
+        });
+        tl.motion(
+            "Corner",
+            Some(Duration::from_secs_f64(2.5)),
+            seeking
+                .move_to_point(dt, mirror.point(tuning.corner))
+                .with_linear_output_limit(4.0),
+        )
+        .await;
+        tl.checkpoint("Corner", mirror.point(tuning.corner), None);
+        tl.action("Intake on", || self.intake.set_voltage(12.0));
+        tl.wait("Corner", Duration::from_millis(800)).await;
+
+        tl.motion("Back from corner", timeout, basic.drive_distance(dt, -14.0))
+            .await;
+        tl.action("Raise intake", || _ = self.intake.raise());
+        tl.motion("Corner again", timeout, basic.drive_distance(dt, 12.0))
+            .await;
+        tl.wait("Corner again", Duration::from_millis(800)).await;
+        tl.action("Lower lady brown", || {
+            self.intake.set_bottom_voltage(0.0);
+            self.lady_brown.set_target(Self::LADY_BROWN_LOWERED);
+        });
+
+        // Clear corner
+        check_time(tl, "Clear corner", CLEAR_CORNER_TIME)?;
+        tl.action("Lower intake", || {
+            _ = self.intake.lower();
+            self.intake.set_voltage(-1.0);
+        });
+        tl.motion("Back from corner", timeout, basic.drive_distance(dt, -8.0))
+            .await;
+        tl.motion(
+            "Face wall",
+            timeout,
+            basic.turn_to_heading(dt, mirror.heading(270.0.deg())),
+        )
+        .await;
+        tl.action("Arm down", || {
+            _ = mirror
+                .side(&mut self.left_arm, &mut self.right_arm)
+                .set_high();
+        });
+        tl.wait("Arm down", Duration::from_millis(800)).await;
+        tl.motion(
+            "Sweep corner",
+            timeout,
+            basic
+                .drive_distance_at_heading(dt, 5.0, mirror.heading(35.0.deg()))
+                .without_tolerance_duration(),
+        )
+        .await;
+
+        // Drop goal
+        tl.action("Outtake", || self.intake.set_voltage(-1.5));
+        tl.motion(
+            "Face drop",
+            timeout,
+            basic.turn_to_heading(dt, mirror.heading(135.0.deg())),
+        )
+        .await;
+        if tuning.release_goal_early {
+            tl.action("Release goal", || _ = self.clamp.set_low());
+        }
+        tl.motion(
+            "Back into drop",
+            Some(Duration::from_millis(800)),
+            basic.drive_distance_at_heading(dt, -13.0, mirror.heading(135.0.deg())),
+        )
+        .await;
+        tl.action("Drop goal", || {
+            self.intake.set_voltage(0.0);
+            _ = self.clamp.set_low();
+            _ = mirror
+                .side(&mut self.left_arm, &mut self.right_arm)
+                .set_low();
+        });
+        if !tuning.release_goal_early {
+            tl.motion(
+                "Push goal",
+                timeout,
+                basic
+                    .drive_distance_at_heading(dt, -4.0, mirror.heading(135.0.deg()))
+                    .with_linear_output_limit(4.0),
+            )
+            .await;
+        }
+
+        // Touch
+        tl.motion(
+            "Touch",
+            timeout,
+            basic.drive_distance_at_heading(
+                dt,
+                tuning.touch_distance,
+                mirror.heading(tuning.touch_heading.deg()),
+            ),
+        )
+        .await;
+        tl.action("Lady brown flat", || {
+            self.lady_brown.set_target(Self::LADY_BROWN_FLAT);
+        });
+
+        Ok(())
+    }
+}
+
+/// Checks that `needed` is left for `name`, only skipping to the ladder if
+/// [`ENFORCE_TIME_BUDGETS`] is set.
+fn check_time(tl: &mut Timeline, name: &'static str, needed: Duration) -> Result<(), StepFailed> {
+    if ENFORCE_TIME_BUDGETS {
+        tl.ensure_time(name, needed)
+    } else {
+        tl.expect_time(name, needed);
+        Ok(())
+    }
+}
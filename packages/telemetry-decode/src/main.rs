@@ -0,0 +1,174 @@
+//! Decodes a captured serial stream into CSV.
+//!
+//! ```console
+//! cargo v5 terminal > capture.bin
+//! cargo host -p telemetry-decode -- capture.bin > capture.csv
+//! ```
+//!
+//! Reads from stdin if no file is given. Every record is written as one row, with columns that
+//! don't apply to the record's type left empty. Text log output mixed into the stream is skipped.
+
+use std::{
+    env,
+    fs::File,
+    io::{self, BufWriter, Read, Write},
+    process::ExitCode,
+};
+
+use telemetry::{DecodeError, Decoder, Record};
+
+const HEADER: &str = "timestamp_ms,record,x,y,heading,left_voltage,right_voltage,top_voltage,bottom_voltage,rejecting,jammed,angle,target";
+
+fn main() -> ExitCode {
+    let input: Box<dyn Read> = match env::args().nth(1) {
+        Some(path) => match File::open(&path) {
+            Ok(file) => Box::new(file),
+            Err(err) => {
+                eprintln!("error: couldn't open {path}: {err}");
+                return ExitCode::FAILURE;
+            }
+        },
+        None => Box::new(io::stdin().lock()),
+    };
+
+    match decode(input, BufWriter::new(io::stdout().lock())) {
+        Ok(stats) => {
+            eprintln!(
+                "{} records decoded, {} corrupt frames, {} chunks of text skipped",
+                stats.records, stats.corrupt, stats.skipped
+            );
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct Stats {
+    records: usize,
+    corrupt: usize,
+    skipped: usize,
+}
+
+fn decode(input: impl Read, mut output: impl Write) -> io::Result<Stats> {
+    let mut decoder = Decoder::new();
+    let mut stats = Stats::default();
+
+    writeln!(output, "{HEADER}")?;
+
+    for byte in io::BufReader::new(input).bytes() {
+        match decoder.push(byte?) {
+            Some(Ok(record)) => {
+                writeln!(output, "{}", row(&record))?;
+                stats.records += 1;
+            }
+            // Text between frames decodes as garbage, so only frames that got as far as the
+            // checksum are counted as corrupt.
+            Some(Err(DecodeError::Crc | DecodeError::UnknownTag(_))) => stats.corrupt += 1,
+            Some(Err(_)) => stats.skipped += 1,
+            None => {}
+        }
+    }
+
+    output.flush()?;
+
+    Ok(stats)
+}
+
+fn row(record: &Record) -> String {
+    match *record {
+        Record::Pose {
+            timestamp,
+            x,
+            y,
+            heading,
+        } => format!("{timestamp},pose,{x},{y},{heading},,,,,,,,"),
+        Record::Drive {
+            timestamp,
+            left_voltage,
+            right_voltage,
+        } => format!("{timestamp},drive,,,,{left_voltage},{right_voltage},,,,,,"),
+        Record::Intake {
+            timestamp,
+            top_voltage,
+            bottom_voltage,
+            rejecting,
+            jammed,
+        } => {
+            format!("{timestamp},intake,,,,,,{top_voltage},{bottom_voltage},{rejecting},{jammed},,")
+        }
+        Record::LadyBrown {
+            timestamp,
+            angle,
+            target,
+        } => format!(
+            "{timestamp},lady_brown,,,,,,,,,,{angle},{}",
+            target.map(|target| target.to_string()).unwrap_or_default()
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use telemetry::MAX_FRAME_SIZE;
+
+    use super::*;
+
+    #[test]
+    fn rows_match_header() {
+        let columns = HEADER.split(',').count();
+
+        for record in [
+            Record::Pose {
+                timestamp: 1,
+                x: 1.0,
+                y: 2.0,
+                heading: 3.0,
+            },
+            Record::Drive {
+                timestamp: 2,
+                left_voltage: 12.0,
+                right_voltage: 12.0,
+            },
+            Record::Intake {
+                timestamp: 3,
+                top_voltage: 12.0,
+                bottom_voltage: 12.0,
+                rejecting: false,
+                jammed: true,
+            },
+            Record::LadyBrown {
+                timestamp: 4,
+                angle: 295.0,
+                target: None,
+            },
+        ] {
+            assert_eq!(row(&record).split(',').count(), columns);
+        }
+    }
+
+    #[test]
+    fn decodes_capture_to_csv() {
+        let record = Record::Pose {
+            timestamp: 500,
+            x: -12.5,
+            y: 30.0,
+            heading: 0.5,
+        };
+
+        let mut capture = b"00:00:500 \x1B[34m[INFO]\x1B[0m Calibrating IMU\n".to_vec();
+        capture.extend_from_slice(record.encode(&mut [0; MAX_FRAME_SIZE]));
+
+        let mut csv = Vec::new();
+        let stats = decode(capture.as_slice(), &mut csv).unwrap();
+
+        assert_eq!(stats.records, 1);
+        assert_eq!(
+            String::from_utf8(csv).unwrap(),
+            format!("{HEADER}\n500,pose,-12.5,30,0.5,,,,,,,,\n")
+        );
+    }
+}
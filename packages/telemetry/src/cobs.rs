@@ -0,0 +1,70 @@
+//! Consistent Overhead Byte Stuffing
+
+/// Returns the worst-case encoded length of `len` bytes of data.
+pub const fn max_encoded_len(len: usize) -> usize {
+    len + len / 254 + 1
+}
+
+/// Encodes `input` into `output`, returning the number of bytes written.
+///
+/// # Panics
+///
+/// Panics if `output` is shorter than [`max_encoded_len`] of the input.
+pub fn encode(input: &[u8], output: &mut [u8]) -> usize {
+    let mut code_index = 0;
+    let mut code = 1u8;
+    let mut len = 1;
+
+    for &byte in input {
+        if byte != 0 {
+            output[len] = byte;
+            len += 1;
+            code += 1;
+        }
+
+        if byte == 0 || code == 0xFF {
+            output[code_index] = code;
+            code_index = len;
+            len += 1;
+            code = 1;
+        }
+    }
+
+    output[code_index] = code;
+
+    len
+}
+
+/// Decodes `input` into `output`, returning the number of bytes written or `None` if the input
+/// isn't valid COBS or doesn't fit.
+pub fn decode(input: &[u8], output: &mut [u8]) -> Option<usize> {
+    let mut index = 0;
+    let mut len = 0;
+
+    while index < input.len() {
+        let code = input[index];
+        if code == 0 {
+            return None;
+        }
+        index += 1;
+
+        for _ in 1..code {
+            let byte = *input.get(index)?;
+            if byte == 0 {
+                return None;
+            }
+
+            *output.get_mut(len)? = byte;
+            len += 1;
+            index += 1;
+        }
+
+        // Every block except the last (and those of maximum length) implies a zero after it.
+        if code != 0xFF && index < input.len() {
+            *output.get_mut(len)? = 0;
+            len += 1;
+        }
+    }
+
+    Some(len)
+}
@@ -0,0 +1,23 @@
+/// Computes the CRC-16/CCITT-FALSE checksum of `data`.
+pub const fn crc16(data: &[u8]) -> u16 {
+    let mut crc = 0xFFFF_u16;
+    let mut i = 0;
+
+    while i < data.len() {
+        crc ^= (data[i] as u16) << 8;
+
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 0x8000 != 0 {
+                (crc << 1) ^ 0x1021
+            } else {
+                crc << 1
+            };
+            bit += 1;
+        }
+
+        i += 1;
+    }
+
+    crc
+}
@@ -0,0 +1,359 @@
+//! Binary Telemetry Protocol
+//!
+//! Telemetry records are sent as frames interleaved with the robot's text log on the same serial
+//! stream. Each frame is laid out as:
+//!
+//! ```text
+//! 0x00 | COBS(tag: u8, timestamp: u32, fields..., crc: u16) | 0x00
+//! ```
+//!
+//! All multi-byte values are little-endian and fields are `f32`s or single-byte flags. The CRC is
+//! CRC-16/CCITT-FALSE over everything before it. COBS encoding guarantees the frame contains no
+//! zero bytes, so the delimiters can be used to resynchronize. Text log lines never contain zero
+//! bytes, so any text between frames shows up as a chunk that fails to decode and is skipped.
+
+#![no_std]
+
+mod cobs;
+mod crc;
+
+use core::fmt;
+
+pub use cobs::{decode as cobs_decode, encode as cobs_encode, max_encoded_len};
+pub use crc::crc16;
+
+/// Size of the largest unencoded record, including its tag, timestamp and CRC.
+pub const MAX_RECORD_SIZE: usize = 1 + 4 + 4 * 4 + 2;
+
+/// Size of the largest frame, including COBS overhead and both delimiters.
+pub const MAX_FRAME_SIZE: usize = max_encoded_len(MAX_RECORD_SIZE) + 2;
+
+/// A single telemetry sample.
+///
+/// Timestamps are milliseconds since program start.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Record {
+    /// Tracked position (in inches) and heading (in radians).
+    Pose {
+        timestamp: u32,
+        x: f32,
+        y: f32,
+        heading: f32,
+    },
+
+    /// Drivetrain motor voltages.
+    Drive {
+        timestamp: u32,
+        left_voltage: f32,
+        right_voltage: f32,
+    },
+
+    /// Intake voltages and state.
+    Intake {
+        timestamp: u32,
+        top_voltage: f32,
+        bottom_voltage: f32,
+        rejecting: bool,
+        jammed: bool,
+    },
+
+    /// Lady brown angle and target (in degrees). The target is `None` under manual control.
+    LadyBrown {
+        timestamp: u32,
+        angle: f32,
+        target: Option<f32>,
+    },
+}
+
+impl Record {
+    const POSE: u8 = 1;
+    const DRIVE: u8 = 2;
+    const INTAKE: u8 = 3;
+    const LADY_BROWN: u8 = 4;
+
+    pub const fn timestamp(&self) -> u32 {
+        match *self {
+            Self::Pose { timestamp, .. }
+            | Self::Drive { timestamp, .. }
+            | Self::Intake { timestamp, .. }
+            | Self::LadyBrown { timestamp, .. } => timestamp,
+        }
+    }
+
+    /// Encodes this record into a delimited frame, returning the written portion of `buf`.
+    pub fn encode<'a>(&self, buf: &'a mut [u8; MAX_FRAME_SIZE]) -> &'a [u8] {
+        let mut raw = Writer::default();
+
+        match *self {
+            Self::Pose {
+                timestamp,
+                x,
+                y,
+                heading,
+            } => {
+                raw.u8(Self::POSE);
+                raw.u32(timestamp);
+                raw.f32(x);
+                raw.f32(y);
+                raw.f32(heading);
+            }
+            Self::Drive {
+                timestamp,
+                left_voltage,
+                right_voltage,
+            } => {
+                raw.u8(Self::DRIVE);
+                raw.u32(timestamp);
+                raw.f32(left_voltage);
+                raw.f32(right_voltage);
+            }
+            Self::Intake {
+                timestamp,
+                top_voltage,
+                bottom_voltage,
+                rejecting,
+                jammed,
+            } => {
+                raw.u8(Self::INTAKE);
+                raw.u32(timestamp);
+                raw.f32(top_voltage);
+                raw.f32(bottom_voltage);
+                raw.u8(u8::from(rejecting) | (u8::from(jammed) << 1));
+            }
+            Self::LadyBrown {
+                timestamp,
+                angle,
+                target,
+            } => {
+                raw.u8(Self::LADY_BROWN);
+                raw.u32(timestamp);
+                raw.f32(angle);
+                raw.f32(target.unwrap_or(f32::NAN));
+            }
+        }
+
+        let crc = crc16(raw.as_slice());
+        raw.u16(crc);
+
+        buf[0] = 0;
+        let len = cobs_encode(raw.as_slice(), &mut buf[1..]);
+        buf[len + 1] = 0;
+
+        &buf[..len + 2]
+    }
+
+    /// Decodes a record from the COBS-encoded contents of a frame (without delimiters).
+    pub fn decode(frame: &[u8]) -> Result<Self, DecodeError> {
+        if frame.len() > max_encoded_len(MAX_RECORD_SIZE) {
+            return Err(DecodeError::TooLong);
+        }
+
+        let mut raw = [0; MAX_RECORD_SIZE];
+        let len = cobs_decode(frame, &mut raw).ok_or(DecodeError::Cobs)?;
+        let raw = &raw[..len];
+
+        if len < 3 {
+            return Err(DecodeError::Truncated);
+        }
+
+        let (body, crc) = raw.split_at(len - 2);
+        if crc16(body) != u16::from_le_bytes([crc[0], crc[1]]) {
+            return Err(DecodeError::Crc);
+        }
+
+        let mut reader = Reader(body);
+        let tag = reader.u8()?;
+        let timestamp = reader.u32()?;
+
+        let record = match tag {
+            Self::POSE => Self::Pose {
+                timestamp,
+                x: reader.f32()?,
+                y: reader.f32()?,
+                heading: reader.f32()?,
+            },
+            Self::DRIVE => Self::Drive {
+                timestamp,
+                left_voltage: reader.f32()?,
+                right_voltage: reader.f32()?,
+            },
+            Self::INTAKE => {
+                let top_voltage = reader.f32()?;
+                let bottom_voltage = reader.f32()?;
+                let flags = reader.u8()?;
+
+                Self::Intake {
+                    timestamp,
+                    top_voltage,
+                    bottom_voltage,
+                    rejecting: flags & 0b01 != 0,
+                    jammed: flags & 0b10 != 0,
+                }
+            }
+            Self::LADY_BROWN => {
+                let angle = reader.f32()?;
+                let target = reader.f32()?;
+
+                Self::LadyBrown {
+                    timestamp,
+                    angle,
+                    target: (!target.is_nan()).then_some(target),
+                }
+            }
+            tag => return Err(DecodeError::UnknownTag(tag)),
+        };
+
+        if !reader.0.is_empty() {
+            return Err(DecodeError::TrailingBytes);
+        }
+
+        Ok(record)
+    }
+}
+
+/// Error returned when a frame can't be decoded into a [`Record`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The frame is longer than any valid record.
+    TooLong,
+
+    /// The frame isn't valid COBS.
+    Cobs,
+
+    /// The frame ended before all of the record's fields.
+    Truncated,
+
+    /// The frame's checksum didn't match its contents.
+    Crc,
+
+    /// The frame has a tag that doesn't correspond to any record.
+    UnknownTag(u8),
+
+    /// The frame has bytes after the end of its record.
+    TrailingBytes,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooLong => write!(f, "frame too long"),
+            Self::Cobs => write!(f, "invalid COBS encoding"),
+            Self::Truncated => write!(f, "frame truncated"),
+            Self::Crc => write!(f, "checksum mismatch"),
+            Self::UnknownTag(tag) => write!(f, "unknown record tag {tag}"),
+            Self::TrailingBytes => write!(f, "trailing bytes after record"),
+        }
+    }
+}
+
+/// Incrementally splits a byte stream into frames and decodes them.
+#[derive(Debug, Clone)]
+pub struct Decoder {
+    buf: [u8; max_encoded_len(MAX_RECORD_SIZE)],
+    len: usize,
+    overflowed: bool,
+}
+
+impl Decoder {
+    pub const fn new() -> Self {
+        Self {
+            buf: [0; max_encoded_len(MAX_RECORD_SIZE)],
+            len: 0,
+            overflowed: false,
+        }
+    }
+
+    /// Feeds a byte into the decoder, returning the result of decoding a frame if this byte
+    /// ended one.
+    ///
+    /// Empty frames (such as between the trailing and leading delimiters of two frames) are
+    /// ignored.
+    pub fn push(&mut self, byte: u8) -> Option<Result<Record, DecodeError>> {
+        if byte != 0 {
+            if self.len < self.buf.len() {
+                self.buf[self.len] = byte;
+                self.len += 1;
+            } else {
+                self.overflowed = true;
+            }
+
+            return None;
+        }
+
+        let result = match (self.len, self.overflowed) {
+            (0, false) => None,
+            (_, true) => Some(Err(DecodeError::TooLong)),
+            (len, false) => Some(Record::decode(&self.buf[..len])),
+        };
+
+        self.len = 0;
+        self.overflowed = false;
+
+        result
+    }
+}
+
+impl Default for Decoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Default)]
+struct Writer {
+    buf: [u8; MAX_RECORD_SIZE],
+    len: usize,
+}
+
+impl Writer {
+    fn bytes(&mut self, bytes: &[u8]) {
+        self.buf[self.len..self.len + bytes.len()].copy_from_slice(bytes);
+        self.len += bytes.len();
+    }
+
+    fn u8(&mut self, value: u8) {
+        self.bytes(&[value]);
+    }
+
+    fn u16(&mut self, value: u16) {
+        self.bytes(&value.to_le_bytes());
+    }
+
+    fn u32(&mut self, value: u32) {
+        self.bytes(&value.to_le_bytes());
+    }
+
+    fn f32(&mut self, value: f32) {
+        self.bytes(&value.to_le_bytes());
+    }
+
+    fn as_slice(&self) -> &[u8] {
+        &self.buf[..self.len]
+    }
+}
+
+struct Reader<'a>(&'a [u8]);
+
+impl Reader<'_> {
+    fn bytes<const N: usize>(&mut self) -> Result<[u8; N], DecodeError> {
+        let (bytes, rest) = self
+            .0
+            .split_first_chunk::<N>()
+            .ok_or(DecodeError::Truncated)?;
+        self.0 = rest;
+
+        Ok(*bytes)
+    }
+
+    fn u8(&mut self) -> Result<u8, DecodeError> {
+        Ok(self.bytes::<1>()?[0])
+    }
+
+    fn u32(&mut self) -> Result<u32, DecodeError> {
+        Ok(u32::from_le_bytes(self.bytes()?))
+    }
+
+    fn f32(&mut self) -> Result<f32, DecodeError> {
+        Ok(f32::from_le_bytes(self.bytes()?))
+    }
+}
@@ -0,0 +1,112 @@
+use telemetry::{
+    cobs_decode, cobs_encode, max_encoded_len, DecodeError, Decoder, Record, MAX_FRAME_SIZE,
+};
+
+fn records() -> [Record; 5] {
+    [
+        Record::Pose {
+            timestamp: 1234,
+            x: -31.5,
+            y: 18.25,
+            heading: 2.25,
+        },
+        Record::Drive {
+            timestamp: 0,
+            left_voltage: 12.0,
+            right_voltage: -12.0,
+        },
+        Record::Intake {
+            timestamp: u32::MAX,
+            top_voltage: 12.0,
+            bottom_voltage: -1.0,
+            rejecting: true,
+            jammed: false,
+        },
+        Record::LadyBrown {
+            timestamp: 15_000,
+            angle: 269.5,
+            target: Some(170.0),
+        },
+        Record::LadyBrown {
+            timestamp: 15_005,
+            angle: 268.0,
+            target: None,
+        },
+    ]
+}
+
+fn decode_stream(bytes: &[u8]) -> Vec<Result<Record, DecodeError>> {
+    let mut decoder = Decoder::new();
+    bytes
+        .iter()
+        .filter_map(|&byte| decoder.push(byte))
+        .collect()
+}
+
+#[test]
+fn records_round_trip() {
+    for record in records() {
+        let mut buf = [0; MAX_FRAME_SIZE];
+        let frame = record.encode(&mut buf);
+
+        assert_eq!(frame.first(), Some(&0));
+        assert_eq!(frame.last(), Some(&0));
+        assert!(!frame[1..frame.len() - 1].contains(&0));
+        assert_eq!(Record::decode(&frame[1..frame.len() - 1]), Ok(record));
+    }
+}
+
+#[test]
+fn stream_round_trips_with_text_between_frames() {
+    let mut stream = Vec::new();
+
+    for record in records() {
+        stream.extend_from_slice(
+            b"00:01:250 \x1B[34m[INFO]\x1B[0m Rejected Blue ring with hue 220.\n",
+        );
+
+        let mut buf = [0; MAX_FRAME_SIZE];
+        stream.extend_from_slice(record.encode(&mut buf));
+    }
+
+    let decoded: Vec<_> = decode_stream(&stream)
+        .into_iter()
+        .filter_map(Result::ok)
+        .collect();
+
+    assert_eq!(decoded, records());
+}
+
+#[test]
+fn corrupted_frame_fails_crc() {
+    let mut buf = [0; MAX_FRAME_SIZE];
+    let mut frame = records()[0].encode(&mut buf).to_vec();
+
+    // Flip a bit in a data byte without introducing a zero.
+    frame[4] ^= if frame[4] == 1 { 2 } else { 1 };
+
+    assert_eq!(decode_stream(&frame), [Err(DecodeError::Crc)]);
+}
+
+#[test]
+fn long_text_chunk_is_rejected() {
+    let mut stream = vec![b'a'; 500];
+    stream.push(0);
+
+    assert_eq!(decode_stream(&stream), [Err(DecodeError::TooLong)]);
+}
+
+#[test]
+fn cobs_round_trips() {
+    let inputs: [&[u8]; 5] = [&[], &[0], &[0, 0, 1, 0], &[1; 254], &[7; 600]];
+
+    for input in inputs {
+        let mut encoded = vec![0; max_encoded_len(input.len())];
+        let len = cobs_encode(input, &mut encoded);
+        assert!(!encoded[..len].contains(&0));
+
+        let mut decoded = vec![0; input.len()];
+        let len = cobs_decode(&encoded[..len], &mut decoded).unwrap();
+        assert_eq!(&decoded[..len], input);
+    }
+}
@@ -0,0 +1,157 @@
+//! Draws a robot's path over the High Stakes field as an SVG.
+//!
+//! ```console
+//! cargo host -p trajectory-svg -- aubie2_20250301_101500_0.log > run.svg
+//! cargo host -p trajectory-svg -- capture.csv --from 00:20 --to 00:35 --alliance blue > auton.svg
+//! ```
+//!
+//! Reads the pose trace from an SD card log, a raw serial capture with telemetry turned on, or the
+//! CSV written by `telemetry-decode`. Reads from stdin if no file is given.
+//!
+//! Intake, jam and lady brown changes are marked along the path where they happened, along with
+//! any actions a route ran through its timeline (such as `Clamp`).
+//!
+//! Options:
+//!
+//! - `--from <MM:SS[:mmm]>` and `--to <MM:SS[:mmm]>`: only draw this part of the run, such as
+//!   autonomous.
+//! - `--alliance <red|blue>`: the alliance the route was run for, which colors the stakes.
+//!   Defaults to red.
+
+mod svg;
+mod trace;
+
+use std::{
+    env,
+    fs::File,
+    io::{self, Read, Write},
+    process::ExitCode,
+    time::Duration,
+};
+
+use log_line::parse_timestamp;
+use svg::Alliance;
+use trace::Trace;
+
+#[derive(Debug, Default)]
+struct Options {
+    path: Option<String>,
+    from: Option<Duration>,
+    to: Option<Duration>,
+    alliance: Alliance,
+}
+
+impl Options {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut options = Self::default();
+
+        while let Some(arg) = args.next() {
+            let mut value = |name: &str| {
+                args.next()
+                    .ok_or_else(|| format!("missing value for {name}"))
+            };
+
+            match arg.as_str() {
+                "--from" => options.from = Some(time_arg(&value("--from")?)?),
+                "--to" => options.to = Some(time_arg(&value("--to")?)?),
+                "--alliance" => {
+                    options.alliance = match value("--alliance")?.to_ascii_lowercase().as_str() {
+                        "red" => Alliance::Red,
+                        "blue" => Alliance::Blue,
+                        alliance => return Err(format!("invalid alliance `{alliance}`")),
+                    }
+                }
+                flag if flag.starts_with('-') => return Err(format!("unknown option {flag}")),
+                _ if options.path.is_none() => options.path = Some(arg),
+                _ => return Err(format!("unexpected argument {arg}")),
+            }
+        }
+
+        Ok(options)
+    }
+}
+
+fn time_arg(value: &str) -> Result<Duration, String> {
+    parse_timestamp(value).ok_or_else(|| format!("invalid time `{value}`, expected MM:SS[:mmm]"))
+}
+
+fn main() -> ExitCode {
+    let options = match Options::parse(env::args().skip(1)) {
+        Ok(options) => options,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut capture = Vec::new();
+    let read = match &options.path {
+        Some(path) => File::open(path).and_then(|mut file| file.read_to_end(&mut capture)),
+        None => io::stdin().lock().read_to_end(&mut capture),
+    };
+    if let Err(err) = read {
+        eprintln!(
+            "error: couldn't read {}: {err}",
+            options.path.as_deref().unwrap_or("stdin")
+        );
+        return ExitCode::FAILURE;
+    }
+
+    let mut trace = Trace::read(&capture);
+    trace.window(options.from, options.to);
+
+    if trace.poses.is_empty() {
+        eprintln!("error: no poses found, is telemetry being recorded?");
+        return ExitCode::FAILURE;
+    }
+
+    let svg = svg::render(&trace, options.alliance, &title(&options, &trace));
+    if let Err(err) = io::stdout().lock().write_all(svg.as_bytes()) {
+        eprintln!("error: {err}");
+        return ExitCode::FAILURE;
+    }
+
+    eprintln!(
+        "{} poses and {} events drawn",
+        trace.poses.len(),
+        trace.events.len()
+    );
+    ExitCode::SUCCESS
+}
+
+fn title(options: &Options, trace: &Trace) -> String {
+    let name = options.path.as_deref().unwrap_or("stdin");
+    let (Some(first), Some(last)) = (trace.poses.first(), trace.poses.last()) else {
+        return name.to_string();
+    };
+
+    format!(
+        "{name}: {:.2}s to {:.2}s",
+        first.time.as_secs_f64(),
+        last.time.as_secs_f64()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_options() {
+        let options = Options::parse(
+            ["run.log", "--from", "00:20", "--alliance", "Blue"]
+                .into_iter()
+                .map(ToString::to_string),
+        )
+        .unwrap();
+
+        assert_eq!(options.path.as_deref(), Some("run.log"));
+        assert_eq!(options.from, Some(Duration::from_secs(20)));
+        assert_eq!(options.to, None);
+        assert_eq!(options.alliance, Alliance::Blue);
+
+        assert!(
+            Options::parse(["--alliance", "green"].into_iter().map(ToString::to_string)).is_err()
+        );
+    }
+}
@@ -0,0 +1,260 @@
+//! Draws a [`Trace`] over a to-scale High Stakes field.
+//!
+//! Positions are in the routes' field frame: origin at the center of the field, the route's own
+//! alliance wall along the bottom (y = -72), x to the right and headings counterclockwise from
+//! +x, all in inches.
+
+use std::fmt::Write;
+
+use crate::trace::{EventKind, Pose, Trace};
+
+/// Half the width of the field, in inches.
+const HALF_FIELD: f64 = 72.0;
+
+/// Size of a foam tile, in inches.
+const TILE: f64 = 24.0;
+
+/// Pixels per inch.
+const SCALE: f64 = 5.0;
+
+/// Space around the field for labels, in pixels.
+const MARGIN: f64 = 40.0;
+
+/// Size of the robot outline drawn at the start and end of the path, in inches.
+const ROBOT_SIZE: f64 = 18.0;
+
+const RED: &str = "#d42a2a";
+const BLUE: &str = "#285ad4";
+
+/// Which alliance the route was run for, which colors the alliance stakes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Alliance {
+    #[default]
+    Red,
+    Blue,
+}
+
+impl Alliance {
+    const fn colors(self) -> (&'static str, &'static str) {
+        match self {
+            Self::Red => (RED, BLUE),
+            Self::Blue => (BLUE, RED),
+        }
+    }
+}
+
+/// Converts a field position in inches to SVG coordinates.
+fn point(x: f64, y: f64) -> (f64, f64) {
+    (
+        MARGIN + (x + HALF_FIELD) * SCALE,
+        MARGIN + (HALF_FIELD - y) * SCALE,
+    )
+}
+
+pub fn render(trace: &Trace, alliance: Alliance, title: &str) -> String {
+    let size = 2.0 * (MARGIN + HALF_FIELD * SCALE);
+    let mut svg = String::new();
+
+    _ = writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{size}" height="{size}" viewBox="0 0 {size} {size}" font-family="sans-serif" font-size="11">"#
+    );
+    _ = writeln!(svg, r#"<rect width="100%" height="100%" fill="white"/>"#);
+    _ = writeln!(
+        svg,
+        r#"<text x="{MARGIN}" y="{}" font-size="14">{}</text>"#,
+        MARGIN - 14.0,
+        escape(title)
+    );
+
+    field(&mut svg, alliance);
+    path(&mut svg, trace);
+    events(&mut svg, trace);
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+fn field(svg: &mut String, alliance: Alliance) {
+    let (own, opponent) = alliance.colors();
+    let (left, top) = point(-HALF_FIELD, HALF_FIELD);
+    let width = 2.0 * HALF_FIELD * SCALE;
+
+    // Tiles
+    _ = writeln!(
+        svg,
+        r##"<rect x="{left}" y="{top}" width="{width}" height="{width}" fill="#d9d9d9"/>"##
+    );
+    for line in 1..6 {
+        let offset = f64::from(line) * TILE * SCALE;
+        _ = writeln!(
+            svg,
+            r##"<path d="M{} {top}v{width}M{left} {}h{width}" stroke="#bdbdbd"/>"##,
+            left + offset,
+            top + offset,
+        );
+    }
+
+    // Autonomous line
+    let (x, y) = point(-HALF_FIELD, 0.0);
+    _ = writeln!(
+        svg,
+        r##"<path d="M{x} {y}h{width}" stroke="white" stroke-width="{}"/>"##,
+        SCALE
+    );
+
+    // Ladder, with the high stake at its center
+    let corners = [(TILE, 0.0), (0.0, TILE), (-TILE, 0.0), (0.0, -TILE)]
+        .map(|(x, y)| {
+            let (x, y) = point(x, y);
+            format!("{x},{y}")
+        })
+        .join(" ");
+    _ = writeln!(
+        svg,
+        r##"<polygon points="{corners}" fill="none" stroke="#555" stroke-width="{}"/>"##,
+        SCALE
+    );
+    stake(svg, 0.0, 0.0, "#f2c200");
+
+    // Wall stakes
+    stake(svg, 0.0, -HALF_FIELD, own);
+    stake(svg, 0.0, HALF_FIELD, opponent);
+    stake(svg, -HALF_FIELD, 0.0, "#f2c200");
+    stake(svg, HALF_FIELD, 0.0, "#f2c200");
+
+    // Perimeter
+    _ = writeln!(
+        svg,
+        r#"<rect x="{left}" y="{top}" width="{width}" height="{width}" fill="none" stroke="black" stroke-width="3"/>"#
+    );
+}
+
+fn stake(svg: &mut String, x: f64, y: f64, color: &str) {
+    let (x, y) = point(x, y);
+    _ = writeln!(
+        svg,
+        r#"<circle cx="{x}" cy="{y}" r="{}" fill="{color}" stroke="black"/>"#,
+        1.5 * SCALE
+    );
+}
+
+fn path(svg: &mut String, trace: &Trace) {
+    let (Some(first), Some(last)) = (trace.poses.first(), trace.poses.last()) else {
+        return;
+    };
+
+    let points = trace
+        .poses
+        .iter()
+        .map(|pose| {
+            let (x, y) = point(pose.x, pose.y);
+            format!("{x:.1},{y:.1}")
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+    _ = writeln!(
+        svg,
+        r##"<polyline points="{points}" fill="none" stroke="#0a8f5a" stroke-width="2.5" stroke-linejoin="round"/>"##
+    );
+
+    robot(svg, first, "#0a8f5a");
+    robot(svg, last, "black");
+}
+
+/// Draws the robot's outline at a pose, with a line showing which way it's facing.
+fn robot(svg: &mut String, pose: &Pose, color: &str) {
+    let (x, y) = point(pose.x, pose.y);
+    let half = ROBOT_SIZE / 2.0 * SCALE;
+    // SVG's y axis points down, so counterclockwise in the field is clockwise on screen.
+    let rotation = -pose.heading.to_degrees();
+
+    _ = writeln!(
+        svg,
+        r#"<g transform="translate({x:.1} {y:.1}) rotate({rotation:.1})" stroke="{color}" fill="none" stroke-width="2"><rect x="{}" y="{}" width="{}" height="{}"/><path d="M0 0h{half}"/></g>"#,
+        -half,
+        -half,
+        2.0 * half,
+        2.0 * half,
+    );
+}
+
+fn events(svg: &mut String, trace: &Trace) {
+    for event in &trace.events {
+        let Some(pose) = trace.pose_at(event.time) else {
+            continue;
+        };
+        let (x, y) = point(pose.x, pose.y);
+        let color = match event.kind {
+            EventKind::Intake => "#1f77b4",
+            EventKind::LadyBrown => "#8e44ad",
+            EventKind::Jam => RED,
+            EventKind::Action => "#e67e22",
+        };
+
+        _ = writeln!(
+            svg,
+            r#"<circle cx="{x:.1}" cy="{y:.1}" r="4" fill="{color}"><title>{:.2}s</title></circle>"#,
+            event.time.as_secs_f64()
+        );
+        _ = writeln!(
+            svg,
+            r#"<text x="{:.1}" y="{:.1}" fill="{color}">{}</text>"#,
+            x + 6.0,
+            y - 6.0,
+            escape(&event.label)
+        );
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::trace::Event;
+
+    #[test]
+    fn maps_field_frame_to_svg() {
+        assert_eq!(point(-72.0, 72.0), (MARGIN, MARGIN));
+        assert_eq!(point(0.0, 0.0), (MARGIN + 360.0, MARGIN + 360.0));
+        assert_eq!(point(72.0, -72.0), (MARGIN + 720.0, MARGIN + 720.0));
+    }
+
+    #[test]
+    fn draws_path_and_events() {
+        let trace = Trace {
+            poses: vec![
+                Pose {
+                    time: Duration::ZERO,
+                    x: 0.0,
+                    y: -62.0,
+                    heading: 0.0,
+                },
+                Pose {
+                    time: Duration::from_secs(1),
+                    x: 24.0,
+                    y: -48.0,
+                    heading: 1.0,
+                },
+            ],
+            events: vec![Event {
+                time: Duration::from_millis(1500),
+                kind: EventKind::Action,
+                label: "Clamp & go".to_string(),
+            }],
+        };
+
+        let svg = render(&trace, Alliance::Blue, "Run");
+
+        assert!(svg.contains(r#"<polyline points="400.0,710.0 520.0,640.0""#));
+        assert!(svg.contains(">Clamp &amp; go</text>"));
+        assert!(svg.ends_with("</svg>\n"));
+    }
+}
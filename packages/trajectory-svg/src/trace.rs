@@ -0,0 +1,361 @@
+//! Pose traces and subsystem events read from a capture.
+
+use std::time::Duration;
+
+use log_line::{strip_ansi, LogLine};
+use telemetry::{Decoder, Record};
+
+/// Start of the log message `aubie2::route::Timeline` writes when a route runs an action.
+const ACTION_PREFIX: &str = "Action: ";
+
+/// Voltage above which the intake counts as running.
+const INTAKE_ON_VOLTAGE: f32 = 1.0;
+
+/// Change in lady brown target that counts as a new move, in degrees.
+const LADY_BROWN_MOVE: f32 = 1.0;
+
+/// Header written by `telemetry-decode`.
+pub const CSV_HEADER: &str = "timestamp_ms,record,";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Pose {
+    pub time: Duration,
+    pub x: f64,
+    pub y: f64,
+
+    /// Heading in radians, counterclockwise from +x.
+    pub heading: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventKind {
+    Intake,
+    LadyBrown,
+    Jam,
+
+    /// A named action from a route's timeline, such as `Clamp`.
+    Action,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Event {
+    pub time: Duration,
+    pub kind: EventKind,
+    pub label: String,
+}
+
+/// Everything read from a capture, in time order.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Trace {
+    pub poses: Vec<Pose>,
+    pub events: Vec<Event>,
+}
+
+impl Trace {
+    /// Reads a capture in any of the formats the robot's data ends up in: a raw serial capture
+    /// or SD card log (text records with telemetry frames mixed in), or the CSV written by
+    /// `telemetry-decode`.
+    pub fn read(capture: &[u8]) -> Self {
+        if capture.starts_with(CSV_HEADER.as_bytes()) {
+            Self::from_records(
+                String::from_utf8_lossy(capture)
+                    .lines()
+                    .skip(1)
+                    .filter_map(parse_csv_row),
+                [],
+            )
+        } else {
+            let mut decoder = Decoder::new();
+            let records = capture
+                .iter()
+                .filter_map(|&byte| decoder.push(byte)?.ok())
+                .collect::<Vec<_>>();
+
+            Self::from_records(
+                records,
+                capture
+                    .split(|&byte| byte == b'\n')
+                    .filter_map(parse_action),
+            )
+        }
+    }
+
+    /// Builds a trace from telemetry records and timeline actions.
+    pub fn from_records(
+        records: impl IntoIterator<Item = Record>,
+        actions: impl IntoIterator<Item = (Duration, String)>,
+    ) -> Self {
+        let mut trace = Self::default();
+        let mut intake = IntakeState::Off;
+        let mut jammed = false;
+        let mut lady_brown_target = None;
+
+        for record in records {
+            let time = Duration::from_millis(record.timestamp().into());
+            let mut event = |kind, label: &str| {
+                trace.events.push(Event {
+                    time,
+                    kind,
+                    label: label.to_string(),
+                });
+            };
+
+            match record {
+                Record::Pose { x, y, heading, .. } => trace.poses.push(Pose {
+                    time,
+                    x: x.into(),
+                    y: y.into(),
+                    heading: heading.into(),
+                }),
+                Record::Intake {
+                    top_voltage,
+                    bottom_voltage,
+                    jammed: now_jammed,
+                    ..
+                } => {
+                    let state = IntakeState::from_voltages(top_voltage, bottom_voltage);
+                    if state != intake {
+                        event(EventKind::Intake, state.label());
+                        intake = state;
+                    }
+
+                    if now_jammed && !jammed {
+                        event(EventKind::Jam, "Jam");
+                    }
+                    jammed = now_jammed;
+                }
+                Record::LadyBrown {
+                    target: Some(target),
+                    ..
+                } => {
+                    if lady_brown_target
+                        .is_none_or(|last: f32| (target - last).abs() >= LADY_BROWN_MOVE)
+                    {
+                        event(EventKind::LadyBrown, &format!("Lady brown {target:.0}°"));
+                    }
+                    lady_brown_target = Some(target);
+                }
+                Record::LadyBrown { target: None, .. } => lady_brown_target = None,
+                Record::Drive { .. } => {}
+            }
+        }
+
+        trace
+            .events
+            .extend(actions.into_iter().map(|(time, label)| Event {
+                time,
+                kind: EventKind::Action,
+                label,
+            }));
+
+        // Records from different subsystems are sent independently, so they can arrive slightly
+        // out of order.
+        trace.poses.sort_by_key(|pose| pose.time);
+        trace.events.sort_by_key(|event| event.time);
+
+        trace
+    }
+
+    /// Keeps only what happened between `from` and `to`.
+    pub fn window(&mut self, from: Option<Duration>, to: Option<Duration>) {
+        let contains =
+            |time: Duration| from.is_none_or(|from| time >= from) && to.is_none_or(|to| time <= to);
+
+        self.poses.retain(|pose| contains(pose.time));
+        self.events.retain(|event| contains(event.time));
+    }
+
+    /// Returns the last pose at or before `time`, or the first pose if there isn't one.
+    pub fn pose_at(&self, time: Duration) -> Option<Pose> {
+        let index = self.poses.partition_point(|pose| pose.time <= time);
+
+        self.poses.get(index.saturating_sub(1)).copied()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum IntakeState {
+    Off,
+    Intaking,
+    Outtaking,
+}
+
+impl IntakeState {
+    fn from_voltages(top: f32, bottom: f32) -> Self {
+        let voltage = if top.abs() > bottom.abs() {
+            top
+        } else {
+            bottom
+        };
+
+        if voltage > INTAKE_ON_VOLTAGE {
+            Self::Intaking
+        } else if voltage < -INTAKE_ON_VOLTAGE {
+            Self::Outtaking
+        } else {
+            Self::Off
+        }
+    }
+
+    const fn label(self) -> &'static str {
+        match self {
+            Self::Off => "Intake off",
+            Self::Intaking => "Intake on",
+            Self::Outtaking => "Outtake",
+        }
+    }
+}
+
+/// Parses a row written by `telemetry-decode` back into a record.
+fn parse_csv_row(row: &str) -> Option<Record> {
+    let columns = row.split(',').collect::<Vec<_>>();
+    let [timestamp, kind, x, y, heading, left_voltage, right_voltage, top_voltage, bottom_voltage, rejecting, jammed, angle, target] =
+        columns.as_slice()
+    else {
+        return None;
+    };
+    let timestamp = timestamp.parse().ok()?;
+
+    Some(match *kind {
+        "pose" => Record::Pose {
+            timestamp,
+            x: x.parse().ok()?,
+            y: y.parse().ok()?,
+            heading: heading.parse().ok()?,
+        },
+        "drive" => Record::Drive {
+            timestamp,
+            left_voltage: left_voltage.parse().ok()?,
+            right_voltage: right_voltage.parse().ok()?,
+        },
+        "intake" => Record::Intake {
+            timestamp,
+            top_voltage: top_voltage.parse().ok()?,
+            bottom_voltage: bottom_voltage.parse().ok()?,
+            rejecting: rejecting.parse().ok()?,
+            jammed: jammed.parse().ok()?,
+        },
+        "lady_brown" => Record::LadyBrown {
+            timestamp,
+            angle: angle.parse().ok()?,
+            target: target.parse().ok(),
+        },
+        _ => return None,
+    })
+}
+
+/// Parses a timeline action from a log line, such as
+/// `00:21:350 [INFO aubie2::route::timeline] Action: Clamp`.
+fn parse_action(line: &[u8]) -> Option<(Duration, String)> {
+    // Anything up to the last zero byte on the line is a telemetry frame.
+    let start = line
+        .iter()
+        .rposition(|&byte| byte == 0)
+        .map_or(0, |end| end + 1);
+    let line = strip_ansi(&String::from_utf8_lossy(&line[start..]));
+    let line = LogLine::parse(&line)?;
+    let action = line.message.strip_prefix(ACTION_PREFIX)?;
+
+    Some((line.timestamp, action.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use telemetry::MAX_FRAME_SIZE;
+
+    use super::*;
+
+    fn intake(timestamp: u32, voltage: f32, jammed: bool) -> Record {
+        Record::Intake {
+            timestamp,
+            top_voltage: voltage,
+            bottom_voltage: voltage,
+            rejecting: false,
+            jammed,
+        }
+    }
+
+    #[test]
+    fn reads_capture_with_actions() {
+        let mut capture = Vec::new();
+        for record in [
+            Record::Pose {
+                timestamp: 20,
+                x: 10.0,
+                y: -63.0,
+                heading: 3.7,
+            },
+            intake(40, 12.0, false),
+        ] {
+            capture.extend_from_slice(record.encode(&mut [0; MAX_FRAME_SIZE]));
+        }
+        capture.extend_from_slice(
+            b"00:00:030 \x1B[34m[INFO aubie2::route::timeline]\x1B[0m Action: Clamp\n",
+        );
+
+        let trace = Trace::read(&capture);
+
+        assert_eq!(trace.poses.len(), 1);
+        assert_eq!(
+            trace
+                .events
+                .iter()
+                .map(|event| (event.time.as_millis(), event.label.as_str()))
+                .collect::<Vec<_>>(),
+            [(30, "Clamp"), (40, "Intake on")]
+        );
+    }
+
+    #[test]
+    fn reads_decoded_csv() {
+        let csv = "\
+timestamp_ms,record,x,y,heading,left_voltage,right_voltage,top_voltage,bottom_voltage,rejecting,jammed,angle,target
+500,pose,-12.5,30,0.5,,,,,,,,
+520,lady_brown,,,,,,,,,,295,269
+540,lady_brown,,,,,,,,,,290,269.5
+560,lady_brown,,,,,,,,,,200,170
+";
+        let trace = Trace::read(csv.as_bytes());
+
+        assert_eq!(
+            trace.poses,
+            [Pose {
+                time: Duration::from_millis(500),
+                x: -12.5,
+                y: 30.0,
+                heading: 0.5,
+            }]
+        );
+        assert_eq!(
+            trace
+                .events
+                .iter()
+                .map(|event| event.label.as_str())
+                .collect::<Vec<_>>(),
+            ["Lady brown 269°", "Lady brown 170°"]
+        );
+    }
+
+    #[test]
+    fn marks_intake_changes_and_jams() {
+        let trace = Trace::from_records(
+            [
+                intake(0, 0.0, false),
+                intake(20, 12.0, false),
+                intake(40, 12.0, true),
+                intake(60, 12.0, true),
+                intake(80, -6.0, false),
+            ],
+            [],
+        );
+
+        assert_eq!(
+            trace
+                .events
+                .iter()
+                .map(|event| event.label.as_str())
+                .collect::<Vec<_>>(),
+            ["Intake on", "Jam", "Outtake"]
+        );
+    }
+}
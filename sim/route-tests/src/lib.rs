@@ -0,0 +1,296 @@
+//! Route Tests
+//!
+//! Runs the robots' routes on a laptop, with the host `vex-sdk` simulating the drivetrain under
+//! them. A run sets the robot up the same way `main` does on the brain, selects the route and
+//! runs autonomous, so the route goes through its real motions, timeline and tracking. Only the
+//! drivetrain moves, so rings are never picked up and goals are never clamped.
+//!
+//! A robot can only be set up once per process, so `tests/routes.rs` runs each route in its own
+//! copy of the test binary.
+
+use std::{
+    fmt,
+    num::{ParseFloatError, ParseIntError},
+    str::FromStr,
+    time::Duration,
+};
+
+use aubie2::{
+    logger::SerialLogger,
+    route::{self, script::SCRIPT_PATH, StartPose},
+};
+use vex_sdk::{
+    sim::{self, Config, DriveModel, SidewaysWheel},
+    V5_DeviceType,
+};
+use vexide::prelude::*;
+
+/// Robots with routes to test.
+pub const ROBOTS: &[&str] = &["orange", "blue"];
+
+/// Script run by the "Script" routes, standing in for the one on the SD card.
+const SCRIPT: &[u8] = include_bytes!("../scripts/route.txt");
+
+/// Seed for the simulated slip and IMU noise, so every run of a route is the same.
+const SEED: u64 = 2025;
+
+/// How long to keep running after autonomous, so the last of the log is written out.
+const LOG_DRAIN_TIME: Duration = Duration::from_millis(100);
+
+/// Names of a robot's routes, including mirrored copies, in the order they're listed.
+pub fn routes(robot: &str) -> Vec<&'static str> {
+    match robot {
+        "orange" => orange::routes::ROUTES
+            .iter()
+            .map(|route| route.name())
+            .collect(),
+        "blue" => blue::routes::ROUTES
+            .iter()
+            .map(|route| route.name())
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// What happened when a route ran.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RouteRun {
+    /// Where the robot really ended up, as `(x, y, heading)` in inches and degrees, rather than
+    /// where its tracking thinks it is.
+    pub end: (f64, f64, f64),
+
+    /// How long the route took, measured by its timeline.
+    pub elapsed: Duration,
+    pub period: Duration,
+
+    /// Checkpoints the route recorded, with where its tracking put the robot at each, in inches.
+    pub checkpoints: Vec<(String, (f64, f64))>,
+
+    /// Names of the checkpoints the robot was out of tolerance at.
+    pub missed_checkpoints: Vec<String>,
+}
+
+/// Runs one of a robot's routes in the simulator, logging as the robot would over serial.
+///
+/// # Panics
+///
+/// Panics if the robot or route doesn't exist, or if a robot has already been run in this
+/// process.
+pub fn run(robot: &str, route: &str) -> RouteRun {
+    SerialLogger.init("info", false).unwrap();
+
+    match robot {
+        "orange" => {
+            let route = route::find(orange::routes::ROUTES, route).expect("no such route");
+            // Script routes read their start pose from the SD card, so the card is put in before
+            // the robot is placed.
+            sim::start(orange_config(StartPose::from_degrees(0.0, 0.0, 90.0)));
+            sim::start(orange_config(
+                route.start().expect("route has no start pose"),
+            ));
+
+            block_on(async {
+                let mut robot = orange::Robot::new(Peripherals::take().unwrap()).await;
+                assert!(robot.select_route(route.name()));
+                robot.autonomous().await;
+
+                let run = RouteRun::new(route.period(), robot.timeline());
+                sleep(LOG_DRAIN_TIME).await;
+                run
+            })
+        }
+        "blue" => {
+            let route = route::find(blue::routes::ROUTES, route).expect("no such route");
+            sim::start(blue_config(StartPose::from_degrees(0.0, 0.0, 90.0)));
+            sim::start(blue_config(route.start().expect("route has no start pose")));
+
+            block_on(async {
+                let mut robot = blue::Robot::new(Peripherals::take().unwrap()).await;
+                assert!(robot.select_route(route.name()));
+                robot.autonomous().await;
+
+                let run = RouteRun::new(route.period(), robot.timeline());
+                sleep(LOG_DRAIN_TIME).await;
+                run
+            })
+        }
+        _ => panic!("no robot named `{robot}`"),
+    }
+}
+
+impl RouteRun {
+    fn new(period: Duration, timeline: &route::Timeline) -> Self {
+        let state = sim::state();
+
+        Self {
+            end: (state.x, state.y, state.heading.to_degrees()),
+            elapsed: timeline.elapsed(),
+            period,
+            checkpoints: timeline
+                .checkpoints()
+                .iter()
+                .map(|checkpoint| {
+                    let position = checkpoint.actual.position;
+                    (checkpoint.name.to_owned(), (position.x, position.y))
+                })
+                .collect(),
+            missed_checkpoints: timeline
+                .checkpoints()
+                .iter()
+                .filter(|checkpoint| !checkpoint.within_tolerance)
+                .map(|checkpoint| checkpoint.name.to_owned())
+                .collect(),
+        }
+    }
+}
+
+// MARK: Robots
+
+/// Motors on each side of both robots' drivetrains.
+const LEFT_MOTORS: [u8; 4] = [11, 12, 13, 14];
+const RIGHT_MOTORS: [u8; 4] = [17, 18, 19, 20];
+
+fn orange_config(start: StartPose) -> Config {
+    Config {
+        devices: devices(
+            &[1, 2, 7, 15],
+            5,
+            &[
+                (8, V5_DeviceType::kDeviceTypeAbsEncSensor),
+                (21, V5_DeviceType::kDeviceTypeOpticalSensor),
+            ],
+        ),
+        left_motors: LEFT_MOTORS.to_vec(),
+        right_motors: RIGHT_MOTORS.to_vec(),
+        drive_ratio: 36.0 / 48.0,
+        model: DriveModel::from_rpm(orange::Robot::TRACK_WIDTH, 3.25, 450.0),
+        start: start_pose(start),
+        seed: SEED,
+        sideways_wheel: Some(SidewaysWheel {
+            port: 7,
+            diameter: orange::Robot::TRACKING_WHEEL_DIAMETER,
+            offset: orange::Robot::SIDEWAYS_TRACKING_WHEEL_OFFSET,
+            ticks_per_revolution: 8192.0,
+        }),
+        files: vec![(SCRIPT_PATH.to_owned(), SCRIPT.to_vec())],
+    }
+}
+
+fn blue_config(start: StartPose) -> Config {
+    Config {
+        devices: devices(
+            &[1, 2, 3, 10],
+            4,
+            &[
+                (9, V5_DeviceType::kDeviceTypeAbsEncSensor),
+                (15, V5_DeviceType::kDeviceTypeOpticalSensor),
+            ],
+        ),
+        left_motors: LEFT_MOTORS.to_vec(),
+        right_motors: RIGHT_MOTORS.to_vec(),
+        drive_ratio: 1.0,
+        model: DriveModel::from_rpm(blue::Robot::TRACK_WIDTH, blue::Robot::WHEEL_DIAMETER, 600.0),
+        start: start_pose(start),
+        seed: SEED,
+        sideways_wheel: Some(SidewaysWheel {
+            port: 7,
+            diameter: blue::Robot::TRACKING_WHEEL_DIAMETER,
+            offset: blue::Robot::SIDEWAYS_TRACKING_WHEEL_OFFSET,
+            ticks_per_revolution: 8192.0,
+        }),
+        files: vec![(SCRIPT_PATH.to_owned(), SCRIPT.to_vec())],
+    }
+}
+
+/// The drivetrain motors, the other motors at `motors`, the IMU and any other sensors.
+fn devices(motors: &[u8], imu: u8, sensors: &[(u8, V5_DeviceType)]) -> Vec<(u8, V5_DeviceType)> {
+    let mut devices = Vec::new();
+
+    for &port in LEFT_MOTORS.iter().chain(&RIGHT_MOTORS).chain(motors) {
+        devices.push((port, V5_DeviceType::kDeviceTypeMotorSensor));
+    }
+    devices.push((imu, V5_DeviceType::kDeviceTypeImuSensor));
+    devices.extend_from_slice(sensors);
+
+    devices
+}
+
+fn start_pose(start: StartPose) -> (f64, f64, f64) {
+    (
+        start.position.x,
+        start.position.y,
+        start.heading.as_radians(),
+    )
+}
+
+// MARK: Reporting
+
+/// Runs are passed from the child process running a route back to the test as one line of
+/// tab-separated fields: end pose, elapsed and period in microseconds, number of checkpoints, the
+/// name and tracked position of each, then the names of any missed checkpoints.
+impl fmt::Display for RouteRun {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (x, y, heading) = self.end;
+        write!(
+            f,
+            "{x}\t{y}\t{heading}\t{}\t{}\t{}",
+            self.elapsed.as_micros(),
+            self.period.as_micros(),
+            self.checkpoints.len(),
+        )?;
+
+        for (name, (x, y)) in &self.checkpoints {
+            write!(f, "\t{name}\t{x}\t{y}")?;
+        }
+        for name in &self.missed_checkpoints {
+            write!(f, "\t{name}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Error parsing a [`RouteRun`] from its [`Display`](fmt::Display) form.
+#[derive(Debug)]
+pub enum ParseRunError {
+    MissingField,
+    Float(ParseFloatError),
+    Int(ParseIntError),
+}
+
+impl From<ParseFloatError> for ParseRunError {
+    fn from(err: ParseFloatError) -> Self {
+        Self::Float(err)
+    }
+}
+
+impl From<ParseIntError> for ParseRunError {
+    fn from(err: ParseIntError) -> Self {
+        Self::Int(err)
+    }
+}
+
+impl FromStr for RouteRun {
+    type Err = ParseRunError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut fields = s.split('\t');
+        let mut next = || fields.next().ok_or(ParseRunError::MissingField);
+
+        let end = (next()?.parse()?, next()?.parse()?, next()?.parse()?);
+        let elapsed = Duration::from_micros(next()?.parse()?);
+        let period = Duration::from_micros(next()?.parse()?);
+        let count = next()?.parse()?;
+        let checkpoints = (0..count)
+            .map(|_| Ok((next()?.to_owned(), (next()?.parse()?, next()?.parse()?))))
+            .collect::<Result<_, Self::Err>>()?;
+
+        Ok(Self {
+            end,
+            elapsed,
+            period,
+            checkpoints,
+            missed_checkpoints: fields.map(str::to_owned).collect(),
+        })
+    }
+}
@@ -0,0 +1,255 @@
+//! Runs every robot's routes in the simulator and checks that each finishes within its period and
+//! stays on the field, where the robot ends up, how long the route takes and that it's within
+//! tolerance at each of its checkpoints.
+//!
+//! Each route runs in its own copy of this binary, started with [`RUN_VAR`] naming the route.
+//! Routes that are changed on purpose will fail here until their entry in [`EXPECTED`] is
+//! updated; a failing route prints what it measured, and its log.
+
+use std::{
+    env,
+    process::{Command, ExitCode, Output},
+    thread,
+    time::Duration,
+};
+
+use route_tests::{RouteRun, ROBOTS};
+
+/// Set to `<robot>/<route>` to run a single route and report it on stdout.
+const RUN_VAR: &str = "ROUTE_TESTS_RUN";
+
+/// Marks the line a route's [`RouteRun`] is reported on, among its log.
+const RUN_PREFIX: &str = "route-run\t";
+
+/// How far the robot can end up from where it's expected, in inches and degrees.
+const POSITION_TOLERANCE: f64 = 1.0;
+const HEADING_TOLERANCE: f64 = 3.0;
+
+/// How much longer or shorter than expected a route can take.
+const TIME_TOLERANCE: Duration = Duration::from_millis(250);
+
+/// Distance from the center of the field to its walls, in inches.
+const FIELD_EDGE: f64 = 72.0;
+
+/// Where a robot ends up, as `(x, y, heading)` in inches and degrees.
+type Pose = (f64, f64, f64);
+
+/// How every route is expected to run in the simulator: the robot, the route, where the robot
+/// ends up and how long the route takes in seconds.
+///
+/// An entry here doesn't excuse a run that's impossible on a real field: every route also has to
+/// finish within its period and keep its end pose and checkpoints inside the field walls.
+const EXPECTED: &[(&str, &str, Pose, f64)] = &[
+    ("orange", "Skills", (0.0, -34.0, -90.1), 15.00),
+    ("orange", "Red Script", (-23.6, -24.4, 59.9), 2.79),
+    ("orange", "Blue Script", (23.7, -24.4, 120.9), 3.17),
+    ("blue", "Skills", (0.0, -25.2, -90.1), 15.00),
+    ("blue", "Red Script", (-23.6, -24.4, 30.1), 2.83),
+    ("blue", "Blue Script", (23.7, -24.4, 142.9), 2.89),
+];
+
+/// Routes known to run past their period in the simulator, as the robot and the route.
+///
+/// On a field these would be cut off partway through, so there's nothing meaningful to compare
+/// against [`EXPECTED`] until they're shortened, and they're reported as ignored instead. A route
+/// listed here that finishes within its period fails, so it gets taken off and given an entry.
+const OVERRUNS: &[(&str, &str)] = &[
+    ("orange", "Red Safe"),
+    ("orange", "Blue Safe"),
+    ("orange", "Red Rush"),
+    ("orange", "Blue Rush"),
+    ("blue", "Red"),
+    ("blue", "Blue"),
+];
+
+/// What checking a route found.
+enum Outcome {
+    Passed,
+
+    /// The route overran its period, and is listed in [`OVERRUNS`].
+    Ignored,
+    Failed(Vec<String>),
+}
+
+fn main() -> ExitCode {
+    if let Ok(target) = env::var(RUN_VAR) {
+        let (robot, route) = target.split_once('/').expect("expected `<robot>/<route>`");
+        println!("{RUN_PREFIX}{}", route_tests::run(robot, route));
+
+        return ExitCode::SUCCESS;
+    }
+
+    // Like libtest, the first argument that isn't a flag filters which routes run.
+    let filter = env::args().skip(1).find(|arg| !arg.starts_with('-'));
+    let targets = ROBOTS
+        .iter()
+        .flat_map(|&robot| {
+            route_tests::routes(robot)
+                .into_iter()
+                .map(move |route| (robot, route))
+        })
+        .filter(|(robot, route)| {
+            filter
+                .as_ref()
+                .is_none_or(|filter| format!("{robot}/{route}").contains(filter.as_str()))
+        })
+        .collect::<Vec<_>>();
+
+    println!("\nrunning {} routes", targets.len());
+
+    let outputs = thread::scope(|scope| {
+        let children = targets
+            .iter()
+            .map(|&(robot, route)| scope.spawn(move || spawn(robot, route)))
+            .collect::<Vec<_>>();
+
+        children
+            .into_iter()
+            .map(|child| child.join().unwrap())
+            .collect::<Vec<_>>()
+    });
+
+    let mut failed = Vec::new();
+    let mut ignored = 0;
+    for (&(robot, route), output) in targets.iter().zip(&outputs) {
+        let name = format!("{robot}/{route}");
+
+        match check(robot, route, output) {
+            Outcome::Passed => println!("test {name} ... ok"),
+            Outcome::Ignored => {
+                println!("test {name} ... ignored, overruns its period");
+                ignored += 1;
+            }
+            Outcome::Failed(problems) => {
+                println!("test {name} ... FAILED");
+                failed.push((name, problems, output));
+            }
+        }
+    }
+
+    for (name, problems, output) in &failed {
+        println!("\n---- {name} ----");
+        for problem in problems {
+            println!("{problem}");
+        }
+        println!("\nlog:\n{}", String::from_utf8_lossy(&output.stdout));
+        print!("{}", String::from_utf8_lossy(&output.stderr));
+    }
+
+    let result = if failed.is_empty() { "ok" } else { "FAILED" };
+    println!(
+        "\ntest result: {result}. {} passed; {} failed; {ignored} ignored\n",
+        targets.len() - failed.len() - ignored,
+        failed.len()
+    );
+
+    if failed.is_empty() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+/// Runs a route in a copy of this binary.
+fn spawn(robot: &str, route: &str) -> Output {
+    Command::new(env::current_exe().unwrap())
+        .env(RUN_VAR, format!("{robot}/{route}"))
+        .output()
+        .unwrap()
+}
+
+/// Checks a route's run against its period, the field and [`EXPECTED`], returning everything
+/// that's wrong with it.
+fn check(robot: &str, route: &str, output: &Output) -> Outcome {
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let Some(run) = stdout
+        .lines()
+        .find_map(|line| line.strip_prefix(RUN_PREFIX))
+        .and_then(|run| run.parse::<RouteRun>().ok())
+    else {
+        return Outcome::Failed(vec![format!("route didn't finish ({})", output.status)]);
+    };
+
+    let (x, y, heading) = run.end;
+    let measured = format!(
+        "measured: end: ({x:.1}, {y:.1}, {heading:.1}), elapsed: {:.2}",
+        run.elapsed.as_secs_f64()
+    );
+
+    let overruns = run.elapsed > run.period;
+    let listed = OVERRUNS.contains(&(robot, route));
+    if overruns && listed {
+        return Outcome::Ignored;
+    }
+
+    let mut problems = Vec::new();
+    if overruns {
+        problems.push(format!(
+            "took {:.2}s, past its {:?} period",
+            run.elapsed.as_secs_f64(),
+            run.period
+        ));
+    } else if listed {
+        problems.push("finished within its period, but is listed in `OVERRUNS`".to_owned());
+    }
+
+    if !on_field((x, y)) {
+        problems.push(format!("ended outside the field at ({x:.1}, {y:.1})"));
+    }
+    for (name, position) in &run.checkpoints {
+        if !on_field(*position) {
+            problems.push(format!(
+                "tracked outside the field at `{name}` ({:.1}, {:.1})",
+                position.0, position.1
+            ));
+        }
+    }
+
+    if !run.missed_checkpoints.is_empty() {
+        problems.push(format!(
+            "out of tolerance at {} of {} checkpoints: {}",
+            run.missed_checkpoints.len(),
+            run.checkpoints.len(),
+            run.missed_checkpoints.join(", ")
+        ));
+    }
+
+    let Some(&(_, _, (expected_x, expected_y, expected_heading), expected_elapsed)) = EXPECTED
+        .iter()
+        .find(|expected| expected.0 == robot && expected.1 == route)
+    else {
+        problems.push(format!("no entry in `EXPECTED`, {measured}"));
+        return Outcome::Failed(problems);
+    };
+
+    let position_error = f64::hypot(x - expected_x, y - expected_y);
+    let heading_error = ((heading - expected_heading + 180.0).rem_euclid(360.0) - 180.0).abs();
+    if position_error > POSITION_TOLERANCE || heading_error > HEADING_TOLERANCE {
+        problems.push(format!(
+            "ended {position_error:.1} in and {heading_error:.1}° from ({expected_x:.1}, \
+             {expected_y:.1}, {expected_heading:.1})"
+        ));
+    }
+
+    let elapsed_error = run
+        .elapsed
+        .abs_diff(Duration::from_secs_f64(expected_elapsed));
+    if elapsed_error > TIME_TOLERANCE {
+        problems.push(format!(
+            "took {:.2}s, expected {expected_elapsed:.2}s",
+            run.elapsed.as_secs_f64(),
+        ));
+    }
+
+    if problems.is_empty() {
+        Outcome::Passed
+    } else {
+        problems.push(measured);
+        Outcome::Failed(problems)
+    }
+}
+
+/// Whether `(x, y)` is inside the field walls.
+fn on_field((x, y): (f64, f64)) -> bool {
+    x.abs() <= FIELD_EDGE && y.abs() <= FIELD_EDGE
+}
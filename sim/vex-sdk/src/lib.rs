@@ -0,0 +1,119 @@
+//! Host VEX SDK
+//!
+//! Stands in for the `vex-sdk` crate on a laptop, so vexide, evian and the robots' libraries build
+//! and run off the brain. The `sim` workspace patches it in over the real crate.
+//!
+//! The types and function signatures are copied from vex-sdk 0.26.0 (MIT, see `LICENSE.md`), except
+//! that [`map_jump_table!`] generates stubs that return zeroed values instead of calling into VEXos,
+//! the `aapcs` ABI (which only exists on ARM) is replaced by `C`, and the OS version functions
+//! return 0. The functions a robot's drivetrain, sensors, clock, serial and SD card go through are
+//! implemented by [`sim`] instead, and shadow their stubs.
+
+#![no_std]
+#![deny(unsafe_op_in_unsafe_fn)]
+#![allow(non_camel_case_types)]
+#![allow(non_upper_case_globals)]
+#![allow(non_snake_case)]
+// The bindings keep the SDK's own layouts and signatures, and every function here is only as safe
+// as the one on the brain.
+#![allow(
+    clippy::missing_safety_doc,
+    clippy::repr_packed_without_abi,
+    clippy::too_many_arguments
+)]
+#![feature(c_variadic)]
+
+extern crate std;
+
+pub mod abs_enc;
+pub mod adi;
+pub mod ai_vision;
+pub mod arm;
+pub mod battery;
+pub mod competition;
+pub mod controller;
+pub mod device;
+pub mod display;
+pub mod distance;
+pub mod file;
+pub mod generic_radio;
+pub mod generic_serial;
+pub mod gps;
+pub mod imu;
+pub mod led;
+pub mod light_tower;
+pub mod magnet;
+pub mod motor;
+pub mod optical;
+pub mod pneumatic;
+pub mod range;
+pub mod serial;
+pub mod system;
+pub mod task;
+pub mod touch;
+pub mod vision;
+
+pub use abs_enc::*;
+pub use adi::*;
+pub use ai_vision::*;
+pub use arm::*;
+pub use battery::*;
+pub use competition::*;
+pub use controller::*;
+pub use device::*;
+pub use display::*;
+pub use distance::*;
+pub use file::*;
+pub use generic_radio::*;
+pub use generic_serial::*;
+pub use gps::*;
+pub use imu::*;
+pub use led::*;
+pub use light_tower::*;
+pub use magnet::*;
+pub use motor::*;
+pub use optical::*;
+pub use pneumatic::*;
+pub use range::*;
+pub use serial::*;
+pub use system::*;
+pub use task::*;
+pub use touch::*;
+pub use vision::*;
+
+pub mod sim;
+
+// Functions the simulator implements. These take precedence over the stubs glob-imported above.
+pub use sim::sdk::{
+    vexDeviceAdiPortConfigGet, vexDeviceAdiPortConfigSet, vexDeviceAdiValueGet,
+    vexDeviceAdiValueSet, vexDeviceGetByIndex, vexDeviceGetStatus, vexDeviceGetTimestamp,
+    vexDeviceImuDegreesGet, vexDeviceImuHeadingGet, vexDeviceImuRawGyroGet, vexDeviceImuReset,
+    vexDeviceImuStatusGet, vexDeviceMotorActualVelocityGet, vexDeviceMotorGearingGet,
+    vexDeviceMotorGearingSet, vexDeviceMotorPositionGet, vexDeviceMotorPositionReset,
+    vexDeviceMotorPositionSet, vexDeviceMotorReverseFlagGet, vexDeviceMotorReverseFlagSet,
+    vexDeviceMotorVelocitySet, vexDeviceMotorVoltageGet, vexDeviceMotorVoltageSet, vexFileClose,
+    vexFileDriveStatus, vexFileMountSD, vexFileOpen, vexFileOpenCreate, vexFileOpenWrite,
+    vexFileRead, vexFileSeek, vexFileSize, vexFileStatus, vexFileTell, vexSerialReadChar,
+    vexSerialWriteBuffer, vexSerialWriteChar, vexSerialWriteFree, vexSystemHighResTimeGet,
+    vexSystemPowerupTimeGet, vexSystemTimeGet, vexTasksRun,
+};
+
+#[macro_export]
+macro_rules! map_jump_table {
+    (
+        $(
+            $offset:expr =>
+            $(#[$meta:meta])* $vis:vis fn $name:ident($($arg:ident: $arg_ty:ty $(,)?),*) $(-> $ret:ty)?
+        ),+ $(,)?
+    ) => {
+        $(
+            $(#[$meta])*
+            #[doc = "# Safety\nOn the host this is a stub that does nothing and returns a zeroed value."]
+            #[allow(unused_variables)]
+            #[inline]
+            $vis unsafe fn $name($($arg: $arg_ty),*) $(-> $ret)? {
+                unsafe { core::mem::zeroed() }
+            }
+        )+
+    };
+}
@@ -0,0 +1,260 @@
+//! Simulated Brain
+//!
+//! A [`Drive`] model behind the SDK calls a robot makes, so its real motors, tracking and
+//! motions drive the model. Tests describe what's plugged into the brain and where the robot
+//! starts with a [`Config`] passed to [`start`], then run the robot on vexide's executor as usual.
+//!
+//! The clock only moves when the robot's code runs: every trip around the executor's loop and
+//! every read of the clock takes a little simulated time, and the model is stepped whenever the
+//! clock passes [`STEP`]. Runs with the same [`Config`] do exactly the same thing.
+//!
+//! Only the drivetrain moves. Motors on each side set the model's voltages and read back its
+//! wheel travel, the IMU and sideways tracking wheel read its rotation, and everything else
+//! (other motors, ADI outputs) just holds what it was last set to. Motors report positions and
+//! speeds as seen after their reverse flag, so a drivetrain's mixed directions don't need to be
+//! described.
+
+pub(crate) mod sdk;
+
+use core::f64::consts::PI;
+use std::{
+    string::String,
+    sync::{Mutex, PoisonError},
+    time::Duration,
+    vec::Vec,
+};
+
+use drive_sim::{Drive, STEP};
+pub use drive_sim::{DriveModel, DriveState};
+
+use crate::{V5MotorGearset, V5_AdiPortConfiguration, V5_DeviceType, V5_MAX_DEVICE_PORTS};
+
+/// Simulated time taken by each trip around the executor's loop, in microseconds.
+const TASKS_RUN_TIME: u64 = 50;
+
+/// Simulated time taken by each read of the clock, in microseconds.
+///
+/// vexide's executor keeps sleeping tasks in a map keyed by their deadline, so two tasks that
+/// start the same sleep on the same microsecond would lose one of their wakeups. Moving the clock
+/// on every read keeps deadlines apart, like the time spent between reads does on the brain.
+const CLOCK_READ_TIME: u64 = 1;
+
+/// How long the IMU reports that it's calibrating after a reset, in microseconds.
+const IMU_CALIBRATION_TIME: u64 = 1_000_000;
+
+/// Number of the Smart port the brain's own ADI ports are reached through.
+const INTERNAL_ADI_PORT: u8 = 22;
+
+/// What's plugged into the simulated brain, and where the robot starts.
+#[derive(Debug, Clone)]
+pub struct Config {
+    /// Smart port numbers and the devices plugged into them. The brain's ADI ports are always
+    /// present.
+    pub devices: Vec<(u8, V5_DeviceType)>,
+
+    /// Ports of the motors on each side of the drivetrain.
+    pub left_motors: Vec<u8>,
+    pub right_motors: Vec<u8>,
+
+    /// Wheel turns per motor turn.
+    pub drive_ratio: f64,
+    pub model: DriveModel,
+
+    /// Where the robot starts, as `(x, y, heading)` in inches and radians.
+    pub start: (f64, f64, f64),
+    pub seed: u64,
+    pub sideways_wheel: Option<SidewaysWheel>,
+
+    /// Files on the SD card, by path. The card reads as not inserted if there are none.
+    pub files: Vec<(String, Vec<u8>)>,
+}
+
+/// An unpowered tracking wheel at right angles to the drive wheels, on one of the brain's ADI
+/// encoder port pairs.
+#[derive(Debug, Clone, Copy)]
+pub struct SidewaysWheel {
+    /// The encoder's top ADI port, 1 for A through 8 for H.
+    pub port: u8,
+    pub diameter: f64,
+
+    /// Offset from the tracking center, as the robot's tracking is configured with.
+    pub offset: f64,
+
+    /// Raw encoder ticks per turn of the wheel.
+    pub ticks_per_revolution: f64,
+}
+
+/// Starts a new simulation, replacing any previous one.
+pub fn start(config: Config) {
+    let (x, y, heading) = config.start;
+    let drive = Drive::new(config.model, x, y, heading, config.seed);
+
+    *WORLD.lock().unwrap_or_else(PoisonError::into_inner) = Some(World {
+        config,
+        drive,
+        time: 0,
+        motors: [Motor::default(); V5_MAX_DEVICE_PORTS],
+        imus: [Imu::default(); V5_MAX_DEVICE_PORTS],
+        adi_configs: [V5_AdiPortConfiguration::default(); 8],
+        adi_values: [0; 8],
+        encoder_offset: 0.0,
+    });
+}
+
+/// Simulated time since [`start`].
+pub fn time() -> Duration {
+    with_world(|world| Duration::from_micros(world.time))
+}
+
+/// The robot's true state, as opposed to where its tracking thinks it is.
+pub fn state() -> DriveState {
+    with_world(|world| *world.drive.state())
+}
+
+/// The last value set on one of the brain's ADI ports, 1 for A through 8 for H, such as whether
+/// a pneumatic is extended.
+pub fn adi_value(port: u8) -> i32 {
+    with_world(|world| world.adi_values[usize::from(port - 1)])
+}
+
+static WORLD: Mutex<Option<World>> = Mutex::new(None);
+
+fn with_world<T>(f: impl FnOnce(&mut World) -> T) -> T {
+    let mut world = WORLD.lock().unwrap_or_else(PoisonError::into_inner);
+
+    f(world
+        .as_mut()
+        .expect("`vex_sdk::sim::start` wasn't called before using the SDK"))
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Motor {
+    voltage: f64,
+    gearset: V5MotorGearset,
+    reverse: bool,
+
+    /// Ticks subtracted from the motor's travel when its position is read.
+    offset: f64,
+}
+
+impl Motor {
+    fn ticks_per_revolution(&self) -> f64 {
+        match self.gearset {
+            V5MotorGearset::kMotorGearSet_36 => 1800.0,
+            V5MotorGearset::kMotorGearSet_06 => 300.0,
+            _ => 900.0,
+        }
+    }
+
+    fn free_rpm(&self) -> f64 {
+        match self.gearset {
+            V5MotorGearset::kMotorGearSet_36 => 100.0,
+            V5MotorGearset::kMotorGearSet_06 => 600.0,
+            _ => 200.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Imu {
+    /// When the current calibration finishes.
+    calibrated_at: u64,
+
+    /// The model's IMU rotation when the IMU was last reset.
+    zero: f64,
+}
+
+struct World {
+    config: Config,
+    drive: Drive,
+
+    /// Simulated time in microseconds.
+    time: u64,
+    motors: [Motor; V5_MAX_DEVICE_PORTS],
+    imus: [Imu; V5_MAX_DEVICE_PORTS],
+    adi_configs: [V5_AdiPortConfiguration; 8],
+    adi_values: [i32; 8],
+
+    /// Ticks subtracted from the sideways wheel's travel when its encoder is read.
+    encoder_offset: f64,
+}
+
+impl World {
+    /// Moves the clock forward, stepping the model for every [`STEP`] it passes.
+    fn advance(&mut self, micros: u64) {
+        self.time += micros;
+
+        let now = Duration::from_micros(self.time);
+        while self.drive.time() + STEP <= now {
+            let voltages = [
+                self.side_voltage(&self.config.left_motors),
+                self.side_voltage(&self.config.right_motors),
+            ];
+            self.drive.set_voltages(voltages);
+            self.drive.step(STEP);
+        }
+    }
+
+    fn side_voltage(&self, ports: &[u8]) -> f64 {
+        let total = ports
+            .iter()
+            .map(|&port| self.motors[usize::from(port - 1)].voltage)
+            .sum::<f64>();
+
+        total / ports.len().max(1) as f64
+    }
+
+    /// Which side of the drivetrain the motor at `index` is on.
+    fn side(&self, index: usize) -> Option<usize> {
+        let port = u8::try_from(index + 1).ok()?;
+
+        if self.config.left_motors.contains(&port) {
+            Some(0)
+        } else if self.config.right_motors.contains(&port) {
+            Some(1)
+        } else {
+            None
+        }
+    }
+
+    /// Converts a distance or speed at the drive wheels' surface into motor turns.
+    fn motor_turns(&self, inches: f64) -> f64 {
+        inches / (PI * self.config.model.wheel_diameter) / self.config.drive_ratio
+    }
+
+    /// Encoder ticks the motor at `index` has turned through since the simulation started.
+    fn motor_ticks(&self, index: usize) -> f64 {
+        let travel = self
+            .side(index)
+            .map_or(0.0, |side| self.drive.state().wheel_travel[side]);
+
+        self.motor_turns(travel) * self.motors[index].ticks_per_revolution()
+    }
+
+    fn motor_rpm(&self, index: usize) -> f64 {
+        let speed = self
+            .side(index)
+            .map_or(0.0, |side| self.drive.state().wheel_velocities[side]);
+
+        self.motor_turns(speed) * 60.0
+    }
+
+    /// Rotation reported by the IMU at `index`, clockwise in degrees since it was reset.
+    fn imu_rotation(&mut self, index: usize) -> f64 {
+        -(self.drive.imu_rotation() - self.imus[index].zero).to_degrees()
+    }
+
+    /// Raw ticks the sideways wheel's encoder has counted since the simulation started.
+    fn encoder_ticks(&self) -> Option<f64> {
+        let wheel = self.config.sideways_wheel?;
+        let travel = self.drive.state().rotation * wheel.offset;
+
+        Some(travel / (PI * wheel.diameter) * wheel.ticks_per_revolution)
+    }
+
+    fn is_encoder(&self, index: usize) -> bool {
+        self.config
+            .sideways_wheel
+            .is_some_and(|wheel| usize::from(wheel.port - 1) == index)
+    }
+}
@@ -0,0 +1,327 @@
+//! SDK functions backed by the simulation.
+//!
+//! These have the same signatures as the stubs they shadow, so they stay `unsafe` and take
+//! arguments they don't use.
+
+#![allow(unused_variables)]
+
+use core::ffi::{c_char, c_double};
+use std::{
+    boxed::Box,
+    ffi::CStr,
+    io::{self, Write},
+    vec::Vec,
+};
+
+use super::{with_world, CLOCK_READ_TIME, IMU_CALIBRATION_TIME, INTERNAL_ADI_PORT, TASKS_RUN_TIME};
+use crate::{
+    V5MotorGearset, V5_AdiPortConfiguration, V5_DeviceImuRaw, V5_DeviceT, V5_DeviceType, FIL,
+    FRESULT, V5_MAX_DEVICE_PORTS,
+};
+
+/// [`vexDeviceImuStatusGet`] flag set while the IMU is calibrating.
+const IMU_CALIBRATING: u32 = 0b1;
+
+/// [`vexDeviceImuStatusGet`] flag set once the IMU has calibrated, so an idle IMU doesn't read
+/// as one that hasn't reported its status yet.
+const IMU_CALIBRATED: u32 = 0b1_0000;
+
+/// Serial buffer space reported as free. Writes never block, so it's always all of it.
+const SERIAL_BUFFER_SIZE: i32 = 2048;
+
+/// Index of the Smart port a device handle refers to.
+fn index(device: V5_DeviceT) -> usize {
+    device as usize - 1
+}
+
+// MARK: Time
+
+pub unsafe fn vexSystemHighResTimeGet() -> u64 {
+    with_world(|world| {
+        world.advance(CLOCK_READ_TIME);
+        world.time
+    })
+}
+
+pub unsafe fn vexSystemPowerupTimeGet() -> u64 {
+    unsafe { vexSystemHighResTimeGet() }
+}
+
+pub unsafe fn vexSystemTimeGet() -> u32 {
+    with_world(|world| (world.time / 1000) as u32)
+}
+
+pub unsafe fn vexTasksRun() {
+    with_world(|world| world.advance(TASKS_RUN_TIME));
+}
+
+// MARK: Devices
+
+pub unsafe fn vexDeviceGetByIndex(index: u32) -> V5_DeviceT {
+    (index as usize + 1) as V5_DeviceT
+}
+
+pub unsafe fn vexDeviceGetStatus(devices: *mut V5_DeviceType) -> i32 {
+    let devices = unsafe { core::slice::from_raw_parts_mut(devices, V5_MAX_DEVICE_PORTS) };
+    devices.fill(V5_DeviceType::kDeviceTypeNoSensor);
+    devices[usize::from(INTERNAL_ADI_PORT - 1)] = V5_DeviceType::kDeviceTypeAdiSensor;
+
+    with_world(|world| {
+        for &(port, device_type) in &world.config.devices {
+            devices[usize::from(port - 1)] = device_type;
+        }
+    });
+
+    devices
+        .iter()
+        .filter(|&&device| device != V5_DeviceType::kDeviceTypeNoSensor)
+        .count() as i32
+}
+
+pub unsafe fn vexDeviceGetTimestamp(device: V5_DeviceT) -> u32 {
+    unsafe { vexSystemTimeGet() }
+}
+
+// MARK: Motors
+
+pub unsafe fn vexDeviceMotorVoltageSet(device: V5_DeviceT, voltage: i32) {
+    with_world(|world| world.motors[index(device)].voltage = f64::from(voltage) / 1000.0);
+}
+
+pub unsafe fn vexDeviceMotorVoltageGet(device: V5_DeviceT) -> i32 {
+    with_world(|world| (world.motors[index(device)].voltage * 1000.0) as i32)
+}
+
+/// Velocity control is modeled as the voltage that would reach `velocity` with no load, which
+/// also makes a braking motor (0 RPM) stop driving.
+pub unsafe fn vexDeviceMotorVelocitySet(device: V5_DeviceT, velocity: i32) {
+    with_world(|world| {
+        let motor = &mut world.motors[index(device)];
+        motor.voltage = f64::from(velocity) / motor.free_rpm() * 12.0;
+    });
+}
+
+pub unsafe fn vexDeviceMotorGearingSet(device: V5_DeviceT, gearset: V5MotorGearset) {
+    with_world(|world| world.motors[index(device)].gearset = gearset);
+}
+
+pub unsafe fn vexDeviceMotorGearingGet(device: V5_DeviceT) -> V5MotorGearset {
+    with_world(|world| world.motors[index(device)].gearset)
+}
+
+pub unsafe fn vexDeviceMotorReverseFlagSet(device: V5_DeviceT, reverse: bool) {
+    with_world(|world| world.motors[index(device)].reverse = reverse);
+}
+
+pub unsafe fn vexDeviceMotorReverseFlagGet(device: V5_DeviceT) -> bool {
+    with_world(|world| world.motors[index(device)].reverse)
+}
+
+pub unsafe fn vexDeviceMotorPositionGet(device: V5_DeviceT) -> c_double {
+    with_world(|world| {
+        let index = index(device);
+        world.motor_ticks(index) - world.motors[index].offset
+    })
+}
+
+pub unsafe fn vexDeviceMotorPositionSet(device: V5_DeviceT, position: c_double) {
+    with_world(|world| {
+        let index = index(device);
+        world.motors[index].offset = world.motor_ticks(index) - position;
+    });
+}
+
+pub unsafe fn vexDeviceMotorPositionReset(device: V5_DeviceT) {
+    unsafe { vexDeviceMotorPositionSet(device, 0.0) }
+}
+
+pub unsafe fn vexDeviceMotorActualVelocityGet(device: V5_DeviceT) -> c_double {
+    with_world(|world| world.motor_rpm(index(device)))
+}
+
+// MARK: IMU
+
+pub unsafe fn vexDeviceImuReset(device: V5_DeviceT) {
+    with_world(|world| {
+        let index = index(device);
+        world.imus[index].calibrated_at = world.time + IMU_CALIBRATION_TIME;
+        world.imus[index].zero = world.drive.imu_rotation();
+    });
+}
+
+pub unsafe fn vexDeviceImuStatusGet(device: V5_DeviceT) -> u32 {
+    with_world(|world| {
+        if world.time < world.imus[index(device)].calibrated_at {
+            IMU_CALIBRATING
+        } else {
+            IMU_CALIBRATED
+        }
+    })
+}
+
+pub unsafe fn vexDeviceImuHeadingGet(device: V5_DeviceT) -> c_double {
+    with_world(|world| world.imu_rotation(index(device)))
+}
+
+pub unsafe fn vexDeviceImuDegreesGet(device: V5_DeviceT) -> c_double {
+    with_world(|world| world.imu_rotation(index(device)).rem_euclid(360.0))
+}
+
+/// Only the Z axis, which counts counterclockwise turns as positive, is simulated.
+pub unsafe fn vexDeviceImuRawGyroGet(device: V5_DeviceT, data: *mut V5_DeviceImuRaw) {
+    let z = with_world(|world| world.drive.imu_rate().to_degrees());
+
+    unsafe {
+        *data = V5_DeviceImuRaw {
+            z,
+            ..Default::default()
+        };
+    }
+}
+
+// MARK: ADI
+
+pub unsafe fn vexDeviceAdiPortConfigSet(
+    device: V5_DeviceT,
+    port: u32,
+    config: V5_AdiPortConfiguration,
+) {
+    with_world(|world| world.adi_configs[port as usize] = config);
+}
+
+pub unsafe fn vexDeviceAdiPortConfigGet(device: V5_DeviceT, port: u32) -> V5_AdiPortConfiguration {
+    with_world(|world| world.adi_configs[port as usize])
+}
+
+pub unsafe fn vexDeviceAdiValueSet(device: V5_DeviceT, port: u32, value: i32) {
+    with_world(|world| match world.encoder_ticks() {
+        Some(ticks) if world.is_encoder(port as usize) => {
+            world.encoder_offset = ticks - f64::from(value);
+        }
+        _ => world.adi_values[port as usize] = value,
+    });
+}
+
+pub unsafe fn vexDeviceAdiValueGet(device: V5_DeviceT, port: u32) -> i32 {
+    with_world(|world| match world.encoder_ticks() {
+        Some(ticks) if world.is_encoder(port as usize) => {
+            (ticks - world.encoder_offset).round() as i32
+        }
+        _ => world.adi_values[port as usize],
+    })
+}
+
+// MARK: Serial
+
+pub unsafe fn vexSerialWriteBuffer(channel: u32, data: *const u8, data_len: u32) -> i32 {
+    let data = unsafe { core::slice::from_raw_parts(data, data_len as usize) };
+    _ = io::stdout().write_all(data);
+
+    data_len as i32
+}
+
+pub unsafe fn vexSerialWriteChar(channel: u32, c: u8) -> i32 {
+    _ = io::stdout().write_all(&[c]);
+
+    1
+}
+
+pub unsafe fn vexSerialWriteFree(channel: u32) -> i32 {
+    SERIAL_BUFFER_SIZE
+}
+
+/// Nothing is ever typed into the simulated terminal.
+pub unsafe fn vexSerialReadChar(channel: u32) -> i32 {
+    -1
+}
+
+// MARK: SD Card
+
+/// A file opened for reading. The SD card is read-only in simulation.
+struct OpenFile {
+    data: Vec<u8>,
+    position: usize,
+}
+
+fn file(fdp: *mut FIL) -> &'static mut OpenFile {
+    unsafe { &mut *fdp.cast::<OpenFile>() }
+}
+
+fn find_file(filename: *const c_char) -> Option<Vec<u8>> {
+    let filename = unsafe { CStr::from_ptr(filename) }.to_str().ok()?;
+
+    with_world(|world| {
+        world
+            .config
+            .files
+            .iter()
+            .find(|(path, _)| path == filename)
+            .map(|(_, data)| data.clone())
+    })
+}
+
+pub unsafe fn vexFileDriveStatus(drive: u32) -> bool {
+    with_world(|world| !world.config.files.is_empty())
+}
+
+pub unsafe fn vexFileMountSD() -> FRESULT {
+    if unsafe { vexFileDriveStatus(0) } {
+        FRESULT::FR_OK
+    } else {
+        FRESULT::FR_NOT_READY
+    }
+}
+
+pub unsafe fn vexFileStatus(filename: *const c_char) -> u32 {
+    u32::from(find_file(filename).is_some())
+}
+
+pub unsafe fn vexFileOpen(filename: *const c_char, mode: *const c_char) -> *mut FIL {
+    match find_file(filename) {
+        Some(data) => Box::into_raw(Box::new(OpenFile { data, position: 0 })).cast(),
+        None => core::ptr::null_mut(),
+    }
+}
+
+pub unsafe fn vexFileOpenWrite(filename: *const c_char) -> *mut FIL {
+    core::ptr::null_mut()
+}
+
+pub unsafe fn vexFileOpenCreate(filename: *const c_char) -> *mut FIL {
+    core::ptr::null_mut()
+}
+
+pub unsafe fn vexFileClose(fdp: *mut FIL) {
+    drop(unsafe { Box::from_raw(fdp.cast::<OpenFile>()) });
+}
+
+pub unsafe fn vexFileSize(fdp: *mut FIL) -> i32 {
+    file(fdp).data.len() as i32
+}
+
+pub unsafe fn vexFileTell(fdp: *mut FIL) -> i32 {
+    file(fdp).position as i32
+}
+
+pub unsafe fn vexFileSeek(fdp: *mut FIL, offset: u32, whence: i32) -> FRESULT {
+    let file = file(fdp);
+    let base = match whence {
+        1 => file.position,
+        2 => file.data.len(),
+        _ => 0,
+    };
+    file.position = (base + offset as usize).min(file.data.len());
+
+    FRESULT::FR_OK
+}
+
+pub unsafe fn vexFileRead(buf: *mut c_char, size: u32, nItems: u32, fdp: *mut FIL) -> i32 {
+    let file = file(fdp);
+    let len = ((size * nItems) as usize).min(file.data.len() - file.position);
+
+    let buf = unsafe { core::slice::from_raw_parts_mut(buf.cast::<u8>(), len) };
+    buf.copy_from_slice(&file.data[file.position..][..len]);
+    file.position += len;
+
+    len as i32
+}